@@ -1,21 +1,38 @@
 use std::collections::HashMap;
+use std::io;
 use std::path::Path;
 use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use hyper::{server::conn::Http, service::service_fn, Body, Response};
+use hyper::{server::conn::Http, service::service_fn, Body, Request, Response};
 use rand::distributions::Distribution;
 use reqwest::{Client, Url};
 use ring::digest;
 use serde::{Deserialize, Serialize};
 use tokio::fs;
+use tokio::io::AsyncWriteExt;
 use tokio::net::TcpListener;
 use tokio::sync::oneshot;
 use tokio::sync::Mutex;
+use tokio::time;
 
 const DEFAULT_AUTHORIZATION_ENDPOINT: &str = "https://accounts.spotify.com/authorize";
 const DEFAULT_TOKEN_ENDPOINT: &str = "https://accounts.spotify.com/api/token";
-
-#[derive(Debug, Deserialize)]
+/// Refresh access token this many seconds before it actually expires.
+const EXPIRY_MARGIN_SECS: u64 = 60;
+const DEFAULT_LOGIN_TIMEOUT_SECS: u64 = 300;
+const DEFAULT_SCOPES: &[&str] = &[
+    "streaming",
+    "user-read-email",
+    "user-read-private",
+    "user-read-playback-state",
+    "user-top-read",
+    "user-library-read",
+    "user-library-modify",
+    "user-read-playback-position",
+];
+
+#[derive(Debug, Clone, Deserialize)]
 pub struct AuthorizationConfig {
     pub client_id: String,
     pub redirect_uri: String,
@@ -23,18 +40,17 @@ pub struct AuthorizationConfig {
     pub authorization_endpoint: String,
     #[serde(default = "AuthorizationConfig::default_token_endpoint")]
     pub token_endpoint: String,
+    #[serde(default = "AuthorizationConfig::default_scopes")]
+    pub scopes: Vec<String>,
+    /// Print authorization url and read redirected url from stdin instead of callback server.
+    #[serde(default)]
+    pub no_browser: bool,
+    /// Give up waiting for authorization callback after this many seconds.
+    #[serde(default = "AuthorizationConfig::default_login_timeout_secs")]
+    pub login_timeout_secs: u64,
 }
 
 impl AuthorizationConfig {
-    pub async fn load<P>(path: P) -> anyhow::Result<Self>
-    where
-        P: AsRef<Path>,
-    {
-        let buf = fs::read(path).await?;
-        let result = toml::from_slice(&buf)?;
-        Ok(result)
-    }
-
     fn default_authorization_endpoint() -> String {
         DEFAULT_AUTHORIZATION_ENDPOINT.into()
     }
@@ -42,6 +58,14 @@ impl AuthorizationConfig {
     fn default_token_endpoint() -> String {
         DEFAULT_TOKEN_ENDPOINT.into()
     }
+
+    fn default_scopes() -> Vec<String> {
+        DEFAULT_SCOPES.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn default_login_timeout_secs() -> u64 {
+        DEFAULT_LOGIN_TIMEOUT_SECS
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -51,6 +75,40 @@ struct AccessToken {
     scope: String,
     expires_in: u64,
     refresh_token: String,
+    /// Unix time (in seconds) when `access_token` expires. Not a part of token endpoint response.
+    #[serde(default)]
+    expires_at: u64,
+}
+
+impl AccessToken {
+    fn with_expires_at(mut self) -> Self {
+        self.expires_at = now() + self.expires_in;
+        self
+    }
+
+    fn is_expired(&self) -> bool {
+        now() + EXPIRY_MARGIN_SECS >= self.expires_at
+    }
+
+    /// Scopes in `scopes` but not granted to this token.
+    fn missing_scopes<'a, I>(&self, scopes: I) -> Vec<String>
+    where
+        I: IntoIterator<Item = &'a String>,
+    {
+        let granted = self.scope.split_whitespace().collect::<Vec<_>>();
+        scopes
+            .into_iter()
+            .filter(|s| !granted.contains(&s.as_str()))
+            .cloned()
+            .collect()
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
 }
 
 #[derive(Debug)]
@@ -100,10 +158,7 @@ fn authorization_url(
         .append_pair("redirect_uri", &config.redirect_uri)
         .append_pair("code_challenge_method", "S256")
         .append_pair("code_challenge", code_challenge)
-        .append_pair(
-            "scope",
-            "streaming user-read-email user-read-private user-read-playback-state user-top-read",
-        )
+        .append_pair("scope", &config.scopes.join(" "))
         .append_pair("state", &state);
 
     Ok((url, state))
@@ -119,6 +174,9 @@ where
 {
     let challenge = code_challenge(code_verifier);
     let (url, csrf_token) = authorization_url(config, &challenge)?;
+    if config.no_browser {
+        return read_authorization_code(&url, &csrf_token);
+    }
     (url_callback)(url.to_string());
 
     let redirect_uri = Url::parse(&config.redirect_uri)?;
@@ -131,45 +189,143 @@ where
 
     let (tx, rx) = oneshot::channel();
     let tx = Arc::new(Mutex::new(Some(tx)));
-    let accept = async {
+    let accept = serve_callback(listener, &csrf_token, &tx);
+
+    let timeout = Duration::from_secs(config.login_timeout_secs);
+    tokio::select! {
+        r = accept => {
+            r?;
+            anyhow::bail!("failed to serve code.") // may be unreachable
+        }
+        result = rx => result?,
+        _ = time::sleep(timeout) => {
+            anyhow::bail!("login timed out after {} seconds.", config.login_timeout_secs)
+        }
+    }
+}
+
+type CallbackSender = Arc<Mutex<Option<oneshot::Sender<anyhow::Result<String>>>>>;
+
+/// Accept connections until dropped. Browsers may open several connections. (e.g. favicon)
+async fn serve_callback(
+    listener: TcpListener,
+    csrf_token: &str,
+    tx: &CallbackSender,
+) -> anyhow::Result<()> {
+    loop {
         let (sock, _) = listener.accept().await?;
         let tx = tx.clone();
-        let csrf_token = csrf_token.clone();
+        let csrf_token = csrf_token.to_string();
         let func = service_fn(move |req| {
             let tx = tx.clone();
             let csrf_token = csrf_token.clone();
-            async move {
-                let uri = req.uri();
-                let url = Url::parse("http://example.com/")?.join(&uri.to_string())?;
-
-                let query = url.query_pairs().collect::<HashMap<_, _>>();
-
-                if let (Some(code), Some(state)) = (query.get("code"), query.get("state")) {
-                    if let Some(tx) = tx.lock().await.take() {
-                        if state != &csrf_token {
-                            anyhow::bail!("state mismatch.");
-                        }
-                        tx.send(code.to_string()).ok();
-                        let res = Response::builder().body(Body::from("ok"))?;
-                        return anyhow::Result::<_>::Ok(res);
-                    }
-                }
-
-                let res = Response::builder().status(204).body(Body::empty())?;
-                anyhow::Result::<_>::Ok(res)
+            async move { handle_callback(req, &csrf_token, &tx).await }
+        });
+        tokio::spawn(async move {
+            if let Err(err) = Http::new().serve_connection(sock, func).await {
+                log::debug!("callback connection error: {}", err);
             }
         });
+    }
+}
 
-        Http::new().serve_connection(sock, func).await?;
-        anyhow::Result::<_>::Ok(())
+/// Serve authorization callback. Sends the code or error to `tx` on first callback.
+async fn handle_callback(
+    req: Request<Body>,
+    csrf_token: &str,
+    tx: &CallbackSender,
+) -> anyhow::Result<Response<Body>> {
+    let url = Url::parse("http://example.com/")?.join(&req.uri().to_string())?;
+    let query = url.query_pairs().collect::<HashMap<_, _>>();
+
+    let result = match (query.get("error"), query.get("code"), query.get("state")) {
+        (Some(error), ..) if error == "access_denied" => {
+            Err(anyhow::anyhow!("authorization was denied by user."))
+        }
+        (Some(error), ..) => Err(anyhow::anyhow!("authorization failed: {}", error)),
+        (None, Some(code), Some(state)) if state == csrf_token => Ok(code.to_string()),
+        (None, Some(_), _) => Err(anyhow::anyhow!("state mismatch.")),
+        _ => {
+            let res = Response::builder().status(404).body(Body::empty())?;
+            return Ok(res);
+        }
     };
 
-    tokio::select! {
-        r = accept => {
-            r?;
-            anyhow::bail!("failed to serve code.") // may be unreachable
-        }
-        code = rx => Ok(code?),
+    let (status, body) = match &result {
+        Ok(_) => (
+            200,
+            callback_page("Logged in", "You can close this window now."),
+        ),
+        Err(err) => (400, callback_page("Login failed", &err.to_string())),
+    };
+    if let Some(tx) = tx.lock().await.take() {
+        tx.send(result).ok();
+    }
+    let res = Response::builder()
+        .status(status)
+        .header("content-type", "text/html; charset=utf-8")
+        .body(Body::from(body))?;
+    Ok(res)
+}
+
+fn callback_page(title: &str, message: &str) -> String {
+    let message = message
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;");
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head><meta charset="utf-8"><title>sptty - {title}</title></head>
+<body style="font-family: sans-serif; text-align: center; margin-top: 4em;">
+<h1>{title}</h1>
+<p>{message}</p>
+</body>
+</html>
+"#,
+        title = title,
+        message = message
+    )
+}
+
+fn read_authorization_code(url: &Url, csrf_token: &str) -> anyhow::Result<String> {
+    eprintln!("Open the following url in browser, then paste redirected url (or code) here.");
+    println!("{}", url);
+
+    parse_pasted_code(&read_pasted_line()?, csrf_token)
+}
+
+#[cfg(not(test))]
+fn read_pasted_line() -> io::Result<String> {
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(input)
+}
+
+/// Nothing is pasted in tests, so login always fails instead of reading stdin of test runner.
+#[cfg(test)]
+fn read_pasted_line() -> io::Result<String> {
+    Ok(String::new())
+}
+
+fn parse_pasted_code(input: &str, csrf_token: &str) -> anyhow::Result<String> {
+    let input = input.trim();
+    if input.is_empty() {
+        anyhow::bail!("no code pasted.");
+    }
+    if !input.contains('?') {
+        return Ok(input.into());
+    }
+
+    let url = Url::parse("http://example.com/")?.join(input)?;
+    let query = url.query_pairs().collect::<HashMap<_, _>>();
+    if let Some(error) = query.get("error") {
+        anyhow::bail!("authorization failed: {}", error);
+    }
+    match (query.get("code"), query.get("state")) {
+        (Some(code), Some(state)) if state == csrf_token => Ok(code.to_string()),
+        (Some(..), _) => anyhow::bail!("state mismatch."),
+        _ => anyhow::bail!("no code found in pasted url."),
     }
 }
 
@@ -189,6 +345,33 @@ struct RefreshTokenFlowRequest {
     client_id: String,
 }
 
+/// Token endpoint response of refresh token flow.
+///
+/// `refresh_token` and `scope` may be omitted when they are not changed.
+#[derive(Debug, Deserialize)]
+struct RefreshTokenResponse {
+    access_token: String,
+    token_type: String,
+    scope: Option<String>,
+    expires_in: u64,
+    refresh_token: Option<String>,
+}
+
+impl AccessToken {
+    /// Apply refresh response. Keeps current refresh token and scope if omitted.
+    fn refreshed(self, res: RefreshTokenResponse) -> Self {
+        Self {
+            access_token: res.access_token,
+            token_type: res.token_type,
+            scope: res.scope.unwrap_or(self.scope),
+            expires_in: res.expires_in,
+            refresh_token: res.refresh_token.unwrap_or(self.refresh_token),
+            expires_at: 0,
+        }
+        .with_expires_at()
+    }
+}
+
 async fn get_token_from_code(
     config: &AuthorizationConfig,
     code_verifier: &str,
@@ -212,13 +395,13 @@ async fn get_token_from_code(
     }
 
     let result = response.bytes().await?;
-    let token = serde_json::from_slice(&result)?;
-    Ok(token)
+    let token = serde_json::from_slice::<AccessToken>(&result)?;
+    Ok(token.with_expires_at())
 }
 
 async fn refresh_token(
     config: &AuthorizationConfig,
-    token: &AccessToken,
+    token: AccessToken,
 ) -> anyhow::Result<AccessToken> {
     let form = RefreshTokenFlowRequest {
         grant_type: "refresh_token".to_string(),
@@ -236,24 +419,101 @@ async fn refresh_token(
     }
 
     let result = response.bytes().await?;
-    let token = serde_json::from_slice(&result)?;
-    Ok(token)
+    let res = serde_json::from_slice::<RefreshTokenResponse>(&result)?;
+    Ok(token.refreshed(res))
 }
 
-pub async fn authenticate<F>(env: &super::Environment, url_callback: F) -> anyhow::Result<()>
+async fn load_token(env: &super::Environment) -> anyhow::Result<Option<AccessToken>> {
+    #[cfg(feature = "keyring")]
+    match crate::keyring::load(keyring_account(env)).await {
+        Ok(Some(json)) => return Ok(Some(serde_json::from_str(&json)?)),
+        Ok(None) => {}
+        Err(err) => log::debug!("could not load token from keyring: {}", err),
+    }
+    load_token_file(&env.token_cache_path()).await
+}
+
+async fn save_token(env: &super::Environment, token: &AccessToken) -> anyhow::Result<()> {
+    #[cfg(feature = "keyring")]
+    match crate::keyring::store(keyring_account(env), &serde_json::to_string(token)?).await {
+        Ok(()) => return remove_token_file(&env.token_cache_path()).await,
+        Err(err) => log::warn!(
+            "could not store token to keyring. use file instead: {}",
+            err
+        ),
+    }
+    save_token_file(&env.token_cache_path(), token).await
+}
+
+async fn remove_token(env: &super::Environment) -> anyhow::Result<()> {
+    #[cfg(feature = "keyring")]
+    if let Err(err) = crate::keyring::clear(keyring_account(env)).await {
+        log::debug!("could not clear token in keyring: {}", err);
+    }
+    remove_token_file(&env.token_cache_path()).await
+}
+
+#[cfg(feature = "keyring")]
+fn keyring_account(env: &super::Environment) -> &str {
+    env.profile.as_deref().unwrap_or("default")
+}
+
+async fn load_token_file(path: &Path) -> anyhow::Result<Option<AccessToken>> {
+    match fs::read_to_string(path).await {
+        Ok(cache) => Ok(Some(serde_json::from_str(&cache)?)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Write token readable only by owner. Replaces existing file atomically.
+async fn save_token_file(path: &Path, token: &AccessToken) -> anyhow::Result<()> {
+    fs::create_dir_all(path.parent().unwrap()).await?;
+    let json = serde_json::to_string(token)?;
+
+    let tmp = path.with_extension(format!("tmp.{}", std::process::id()));
+    fs::remove_file(&tmp).await.ok();
+    let result = async {
+        let mut file = fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .mode(0o600)
+            .open(&tmp)
+            .await?;
+        file.write_all(json.as_bytes()).await?;
+        file.sync_all().await?;
+        fs::rename(&tmp, path).await
+    }
+    .await;
+    if let Err(err) = result {
+        fs::remove_file(&tmp).await.ok();
+        return Err(err.into());
+    }
+    Ok(())
+}
+
+async fn remove_token_file(path: &Path) -> anyhow::Result<()> {
+    match fs::remove_file(path).await {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err.into()),
+        _ => Ok(()),
+    }
+}
+
+async fn login<F>(config: &AuthorizationConfig, url_callback: F) -> anyhow::Result<AccessToken>
 where
     F: FnMut(String),
 {
-    let cache_path = dirs::cache_dir().unwrap().join("sptty/token");
-    fs::create_dir_all(cache_path.parent().unwrap()).await?;
-
     let verifier = gen_code_verifier();
-    let code = get_authorization_code(&env.auth_config, &verifier, url_callback).await?;
-    let token = get_token_from_code(&env.auth_config, &verifier, &code).await?;
-
-    let json = serde_json::to_string(&token)?;
-    fs::write(&cache_path, json).await?;
+    let code = get_authorization_code(config, &verifier, url_callback).await?;
+    get_token_from_code(config, &verifier, &code).await
+}
 
+pub async fn authenticate<F>(env: &super::Environment, url_callback: F) -> anyhow::Result<()>
+where
+    F: FnMut(String),
+{
+    let token = login(&env.auth_config, url_callback).await?;
+    save_token(env, &token).await?;
     Ok(())
 }
 
@@ -261,23 +521,259 @@ pub async fn get_token<F>(env: &super::Environment, url_callback: F) -> anyhow::
 where
     F: FnMut(String),
 {
-    let cache_path = dirs::cache_dir().unwrap().join("sptty/token");
-    fs::create_dir_all(cache_path.parent().unwrap()).await?;
-
-    if let Ok(cache) = fs::read_to_string(&cache_path).await {
-        let token = serde_json::from_str::<AccessToken>(&cache)?;
-        let token = refresh_token(&env.auth_config, &token).await?;
-        let json = serde_json::to_string(&token)?;
-        fs::write(&cache_path, json).await?;
+    if let Some(token) = load_token(env).await? {
+        // Commands needing a missing scope login again by `RestClient::require_scopes`.
+        let missing = token.missing_scopes(&env.auth_config.scopes);
+        if !missing.is_empty() {
+            log::warn!(
+                "scope(s) {} are not granted yet. run `sptty --login` to grant them.",
+                missing.join(" ")
+            );
+        }
+        if !token.is_expired() {
+            return Ok(token.access_token);
+        }
+        let token = refresh_token(&env.auth_config, token).await?;
+        save_token(env, &token).await?;
         return Ok(token.access_token);
     }
 
-    let verifier = gen_code_verifier();
-    let code = get_authorization_code(&env.auth_config, &verifier, url_callback).await?;
-    let token = get_token_from_code(&env.auth_config, &verifier, &code).await?;
+    let token = login(&env.auth_config, url_callback).await?;
+    save_token(env, &token).await?;
+    Ok(token.access_token)
+}
 
-    let json = serde_json::to_string(&token)?;
-    fs::write(&cache_path, json).await?;
+/// Login again if any of `scopes` is not granted.
+///
+/// Returns new access token if logged in.
+pub async fn ensure_scopes<F>(
+    env: &super::Environment,
+    scopes: &[&str],
+    url_callback: F,
+) -> anyhow::Result<Option<String>>
+where
+    F: FnMut(String),
+{
+    let token = load_token(env)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("not logged in."))?;
+
+    let scopes = scopes.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+    let missing = token.missing_scopes(&scopes);
+    if missing.is_empty() {
+        return Ok(None);
+    }
+    eprintln!(
+        "this command requires scope(s) {} which are not granted yet. login again.",
+        missing.join(" ")
+    );
+
+    let mut config = env.auth_config.clone();
+    config.scopes.extend(missing);
+    let token = login(&config, url_callback).await?;
+    save_token(env, &token).await?;
+    Ok(Some(token.access_token))
+}
 
+/// Refresh cached access token even if it is not expired yet.
+pub async fn refresh_access_token(env: &super::Environment) -> anyhow::Result<String> {
+    let token = load_token(env)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("not logged in."))?;
+    let token = refresh_token(&env.auth_config, token).await?;
+    save_token(env, &token).await?;
     Ok(token.access_token)
 }
+
+/// Cached token information.
+#[derive(Debug)]
+pub struct TokenInfo {
+    pub scopes: Vec<String>,
+    /// Configured scopes not granted to the token.
+    pub missing_scopes: Vec<String>,
+    /// Unix time (in seconds).
+    pub expires_at: u64,
+}
+
+impl TokenInfo {
+    /// Seconds until the access token expires. `None` if already expired.
+    pub fn expires_in(&self) -> Option<u64> {
+        self.expires_at.checked_sub(now()).filter(|secs| *secs > 0)
+    }
+}
+
+pub async fn token_info(env: &super::Environment) -> anyhow::Result<Option<TokenInfo>> {
+    let token = load_token(env).await?;
+    Ok(token.map(|token| TokenInfo {
+        missing_scopes: token.missing_scopes(&env.auth_config.scopes),
+        scopes: token.scope.split_whitespace().map(Into::into).collect(),
+        expires_at: token.expires_at,
+    }))
+}
+
+/// Cached access token, refreshed if expired. Never starts login even if scopes are missing.
+///
+/// Returns `None` if not logged in.
+pub async fn cached_token(env: &super::Environment) -> anyhow::Result<Option<String>> {
+    let token = match load_token(env).await? {
+        Some(token) => token,
+        None => return Ok(None),
+    };
+    if !token.is_expired() {
+        return Ok(Some(token.access_token));
+    }
+    let token = refresh_token(&env.auth_config, token).await?;
+    save_token(env, &token).await?;
+    Ok(Some(token.access_token))
+}
+
+/// Remove cached token and audio cache.
+pub async fn logout(env: &super::Environment) -> anyhow::Result<()> {
+    remove_token(env).await?;
+    match fs::remove_dir_all(env.audio_cache_dir()).await {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err.into()),
+        _ => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::FakeSpotify;
+
+    fn stored_token(expires_at: u64) -> AccessToken {
+        AccessToken {
+            access_token: "old-access".into(),
+            token_type: "Bearer".into(),
+            scope: "streaming user-read-email".into(),
+            expires_in: 3600,
+            refresh_token: "old-refresh".into(),
+            expires_at,
+        }
+    }
+
+    #[tokio::test]
+    async fn refresh_keeps_refresh_token_and_scope_if_omitted() {
+        let fake = FakeSpotify::start().await;
+        fake.route(
+            "POST",
+            "/api/token",
+            200,
+            r#"{"access_token":"new-access","token_type":"Bearer","expires_in":3600}"#,
+        );
+
+        let token = refresh_token(&fake.env.auth_config, stored_token(0))
+            .await
+            .unwrap();
+        assert_eq!(token.access_token, "new-access");
+        assert_eq!(token.refresh_token, "old-refresh");
+        assert_eq!(token.scope, "streaming user-read-email");
+        assert!(!token.is_expired());
+
+        let requests = fake.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].body.contains("grant_type=refresh_token"));
+        assert!(requests[0].body.contains("refresh_token=old-refresh"));
+        assert!(requests[0].body.contains("client_id=client"));
+    }
+
+    #[tokio::test]
+    async fn refresh_uses_rotated_refresh_token() {
+        let fake = FakeSpotify::start().await;
+        fake.route(
+            "POST",
+            "/api/token",
+            200,
+            r#"{"access_token":"new-access","token_type":"Bearer","scope":"streaming","expires_in":3600,"refresh_token":"new-refresh"}"#,
+        );
+
+        let token = refresh_token(&fake.env.auth_config, stored_token(0))
+            .await
+            .unwrap();
+        assert_eq!(token.refresh_token, "new-refresh");
+        assert_eq!(token.scope, "streaming");
+    }
+
+    #[tokio::test]
+    async fn refresh_error_is_reported() {
+        let fake = FakeSpotify::start().await;
+        fake.route("POST", "/api/token", 400, r#"{"error":"invalid_grant"}"#);
+
+        let err = refresh_token(&fake.env.auth_config, stored_token(0))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("invalid_grant"));
+    }
+
+    #[tokio::test]
+    async fn get_token_refreshes_expired_cache() {
+        let fake = FakeSpotify::start().await;
+        fake.login(-10);
+
+        let access_token = get_token(&fake.env, |_| panic!("should not login"))
+            .await
+            .unwrap();
+        assert_eq!(access_token, "refreshed-access");
+
+        let cached = load_token_file(&fake.env.token_cache_path())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(cached.access_token, "refreshed-access");
+        assert_eq!(cached.refresh_token, "cached-refresh");
+
+        // Cached token is used until it expires.
+        let access_token = get_token(&fake.env, |_| panic!("should not login"))
+            .await
+            .unwrap();
+        assert_eq!(access_token, "refreshed-access");
+        assert_eq!(fake.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn get_token_does_not_login_for_missing_scopes() {
+        let fake = FakeSpotify::start().await;
+        fake.login(3600);
+        let mut env = fake.env.clone();
+        env.auth_config
+            .scopes
+            .push("user-read-playback-position".into());
+
+        let access_token = get_token(&env, |_| panic!("should not login"))
+            .await
+            .unwrap();
+        assert_eq!(access_token, "cached-access");
+        assert!(fake.requests().is_empty());
+    }
+
+    #[cfg(feature = "keyring")]
+    #[tokio::test]
+    async fn keyring_falls_back_to_file_without_secret_tool() {
+        let fake = FakeSpotify::start().await;
+        let path = fake.env.token_cache_path();
+
+        save_token(&fake.env, &stored_token(u64::MAX))
+            .await
+            .unwrap();
+        assert!(path.exists());
+        let token = load_token(&fake.env).await.unwrap().unwrap();
+        assert_eq!(token.access_token, "old-access");
+
+        remove_token(&fake.env).await.unwrap();
+        assert!(!path.exists());
+        assert!(load_token(&fake.env).await.unwrap().is_none());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn token_file_is_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("token");
+        save_token_file(&path, &stored_token(0)).await.unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+}
@@ -1,9 +1,11 @@
 use std::env;
 use std::process::Stdio;
+use std::time::Duration;
 
 use tokio::fs;
 use tokio::process::Command;
 use tokio::sync::oneshot;
+use tokio::time;
 
 use super::*;
 
@@ -33,14 +35,14 @@ pub async fn run(env: &Environment) -> anyhow::Result<()> {
         None
     };
 
-    connect(&token, notify).await?;
+    connect(&token, env.audio_cache_dir(), notify).await?;
     Ok(())
 }
 
-pub async fn start() -> anyhow::Result<()> {
-    install().await?;
+pub async fn start(env: &Environment) -> anyhow::Result<()> {
+    install(env).await?;
     let status = Command::new("systemctl")
-        .args(["start", "--user", "sptty"])
+        .args(["start", "--user", &env.agent_unit_name()])
         .stdin(Stdio::null())
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
@@ -53,9 +55,35 @@ pub async fn start() -> anyhow::Result<()> {
     Ok(())
 }
 
-pub async fn kill() -> anyhow::Result<()> {
+/// Start playback agent and make it active device.
+pub async fn activate(env: &Environment) -> anyhow::Result<()> {
+    start(env).await?;
+
+    let client = RestClient::new(env).await?;
+    for _ in 0..20 {
+        let devices = client
+            .request::<_, model::Devices>("/v1/me/player/devices", Method::Get, Empty)
+            .await?;
+        if let Some(device) = devices.devices.iter().find(|d| d.name == DEVICE_NAME) {
+            if !device.is_active {
+                let req = model::TransferUserPlaybackRequest {
+                    device_ids: vec![device.id.clone()],
+                    play: false,
+                };
+                client
+                    .request::<_, Empty>("/v1/me/player", Method::Put, req)
+                    .await?;
+            }
+            return Ok(());
+        }
+        time::sleep(Duration::from_millis(500)).await;
+    }
+    anyhow::bail!("playback agent did not appear as a device.");
+}
+
+pub async fn kill(env: &Environment) -> anyhow::Result<()> {
     let status = Command::new("systemctl")
-        .args(["stop", "--user", "sptty"])
+        .args(["stop", "--user", &env.agent_unit_name()])
         .stdin(Stdio::null())
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
@@ -68,12 +96,15 @@ pub async fn kill() -> anyhow::Result<()> {
     Ok(())
 }
 
-async fn install() -> anyhow::Result<()> {
+async fn install(env: &Environment) -> anyhow::Result<()> {
     let userunit_home = Environment::systemd_user_runtime_dir();
     fs::create_dir_all(&userunit_home).await.ok();
-    let service_file = userunit_home.join("sptty.service");
+    let service_file = userunit_home.join(format!("{}.service", env.agent_unit_name()));
 
-    let me = env::current_exe()?;
+    let mut me = env::current_exe()?.display().to_string();
+    if let Some(profile) = &env.profile {
+        me = format!("{} --profile {}", me, profile);
+    }
     let content = r#"[Unit]
 Description=Lightweight Spotify daemon.
 
@@ -83,7 +114,7 @@ ExecStart=@here agent run
 [Install]
 WantedBy=default.target
 "#;
-    let content = content.replace("@here", &me.display().to_string());
+    let content = content.replace("@here", &me);
 
     fs::write(service_file, content).await?;
     Ok(())
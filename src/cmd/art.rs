@@ -0,0 +1,145 @@
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+
+use clap::ValueEnum;
+use reqwest::{Client, Url};
+use tokio::fs;
+use tokio::process::Command;
+
+use super::*;
+use model::{CurrentlyPlayingContext, Image, TrackOrEpisode};
+
+/// Terminal graphics protocol to render cover art.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Graphics {
+    /// Kitty if running in kitty, otherwise sixel.
+    Auto,
+    /// Kitty graphics protocol. Requires `kitty +kitten icat`.
+    Kitty,
+    /// Sixel. Requires `img2sixel`.
+    Sixel,
+}
+
+/// Download cover art of currently playing item into cache.
+///
+/// Prints cached file path unless rendered by `show`.
+pub async fn art(
+    env: &Environment,
+    size: Option<u64>,
+    out: Option<&Path>,
+    show: Option<Graphics>,
+) -> anyhow::Result<()> {
+    let client = RestClient::new(env).await?;
+    let current_playing = client
+        .request::<_, MayBeEmpty<CurrentlyPlayingContext>>(PLAYBACK_STATE, Method::Get, Empty)
+        .await?;
+    let item = match current_playing {
+        MayBeEmpty::Present(CurrentlyPlayingContext {
+            item: Some(item), ..
+        }) => item,
+        _ => anyhow::bail!("currently not playing."),
+    };
+    let images = match &item {
+        TrackOrEpisode::Track { inner } => inner
+            .album
+            .as_ref()
+            .map(|a| a.images.as_slice())
+            .unwrap_or_default(),
+        TrackOrEpisode::Episode { inner } if inner.images.is_empty() => &inner.show.images,
+        TrackOrEpisode::Episode { inner } => &inner.images,
+        TrackOrEpisode::Local { .. } => &[],
+    };
+    let image = select_image(images, size)
+        .ok_or_else(|| anyhow::anyhow!("no cover art for current item."))?;
+
+    let path = download(env, image).await?;
+    if let Some(out) = out {
+        fs::copy(&path, out).await?;
+    }
+    match show {
+        Some(graphics) => render(graphics, &path).await,
+        None => {
+            println!("{}", out.unwrap_or(&path).display());
+            Ok(())
+        }
+    }
+}
+
+/// Smallest image not smaller than `size`, or largest image if none.
+fn select_image(images: &[Image], size: Option<u64>) -> Option<&Image> {
+    let largest = images.iter().max_by_key(|i| i.size());
+    let size = if let Some(size) = size {
+        size
+    } else {
+        return largest;
+    };
+    images
+        .iter()
+        .filter(|i| i.size() >= size)
+        .min_by_key(|i| i.size())
+        .or(largest)
+}
+
+impl Image {
+    /// Width, or height if width is unknown. Cover arts are square.
+    fn size(&self) -> u64 {
+        self.width.or(self.height).unwrap_or_default()
+    }
+}
+
+/// Download `image` unless cached. Returns cached file path.
+async fn download(env: &Environment, image: &Image) -> anyhow::Result<PathBuf> {
+    let url = Url::parse(&image.url)?;
+    // e.g. https://i.scdn.co/image/ab67616d0000b273...
+    let name = url
+        .path_segments()
+        .and_then(|mut s| s.next_back())
+        .filter(|s| !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric()))
+        .ok_or_else(|| anyhow::anyhow!("unexpected image url: {}", image.url))?;
+    let path = env.art_cache_dir().join(format!("{}.jpg", name));
+    if fs::metadata(&path).await.is_ok() {
+        return Ok(path);
+    }
+
+    let response = Client::new().get(url).send().await?;
+    if !response.status().is_success() {
+        anyhow::bail!("failed to download cover art. ({})", response.status());
+    }
+    let body = response.bytes().await?;
+    fs::create_dir_all(env.art_cache_dir()).await?;
+    fs::write(&path, body).await?;
+    Ok(path)
+}
+
+async fn render(graphics: Graphics, path: &Path) -> anyhow::Result<()> {
+    let graphics = match graphics {
+        Graphics::Auto if is_kitty() => Graphics::Kitty,
+        Graphics::Auto => Graphics::Sixel,
+        g => g,
+    };
+    let mut command = match graphics {
+        Graphics::Kitty => {
+            let mut command = Command::new("kitty");
+            command.args(["+kitten", "icat", "--align", "left"]);
+            command
+        }
+        _ => Command::new("img2sixel"),
+    };
+    let status = command
+        .arg(path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()
+        .await?;
+    if !status.success() {
+        anyhow::bail!("failed to render cover art.");
+    }
+    Ok(())
+}
+
+fn is_kitty() -> bool {
+    env::var_os("KITTY_WINDOW_ID").is_some()
+        || matches!(env::var("TERM"), Ok(t) if t.contains("kitty"))
+}
@@ -0,0 +1,79 @@
+use serde_json::Value;
+
+use super::*;
+use crate::auth::{self, TokenInfo};
+
+struct AuthStatus<'a> {
+    user: &'a model::PublicUser,
+    client_id: &'a str,
+    token: &'a TokenInfo,
+}
+
+impl AuthStatus<'_> {
+    fn user_name(&self) -> &str {
+        self.user.display_name.as_deref().unwrap_or(&self.user.id)
+    }
+
+    fn expires_in(&self) -> String {
+        match self.token.expires_in() {
+            Some(secs) => format!("in {} min", secs / 60),
+            None => "expired".into(),
+        }
+    }
+}
+
+impl Record for AuthStatus<'_> {
+    fn fields(&self) -> Vec<(&'static str, Value)> {
+        vec![
+            ("user", self.user_name().into()),
+            ("user_id", self.user.id.clone().into()),
+            ("client_id", self.client_id.into()),
+            ("scopes", self.token.scopes.join(" ").into()),
+            ("missing_scopes", self.token.missing_scopes.join(" ").into()),
+            ("expires_at", self.token.expires_at.into()),
+        ]
+    }
+
+    fn text(&self) -> String {
+        let mut lines = vec![
+            format!("user:      {} ({})", self.user_name(), self.user.id),
+            format!("client id: {}", self.client_id),
+            format!("scopes:    {}", self.token.scopes.join(" ")),
+        ];
+        if !self.token.missing_scopes.is_empty() {
+            lines.push(format!(
+                "missing:   {} (login again to grant)",
+                self.token.missing_scopes.join(" ")
+            ));
+        }
+        lines.push(format!("expires:   {}", self.expires_in()));
+        lines.join("\n")
+    }
+}
+
+/// Print logged in user and cached token. Never starts login.
+pub async fn status(env: &Environment) -> anyhow::Result<()> {
+    let token = auth::cached_token(env)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("not logged in."))?;
+    let user = RestClient::with_token(env, token)?
+        .request::<_, model::PublicUser>("/v1/me", Method::Get, Empty)
+        .await?;
+    // Reload because the token may have been refreshed.
+    let token = auth::token_info(env)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("not logged in."))?;
+
+    env.output.print(&AuthStatus {
+        user: &user,
+        client_id: &env.auth_config.client_id,
+        token: &token,
+    })
+}
+
+/// Remove cached token and audio cache.
+pub async fn logout(env: &Environment) -> anyhow::Result<()> {
+    auth::logout(env).await?;
+    eprintln!("logged out.");
+    Ok(())
+}
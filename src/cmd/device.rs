@@ -7,11 +7,7 @@ pub async fn list(env: &Environment) -> anyhow::Result<()> {
         .request::<_, Devices>("/v1/me/player/devices", Method::Get, Empty)
         .await?;
     for device in devices.devices {
-        if device.is_active {
-            println!("{} {}✔", device.id, device.name);
-        } else {
-            println!("{} {}", device.id, device.name);
-        }
+        env.output.print(&device)?;
     }
 
     Ok(())
@@ -44,6 +40,10 @@ pub async fn set(env: &Environment, name: &str, play: bool) -> anyhow::Result<()
 }
 
 pub async fn set_by_id(env: &Environment, id: &str, play: bool) -> anyhow::Result<()> {
+    if id.is_empty() || !id.chars().all(|c| c.is_ascii_alphanumeric()) {
+        anyhow::bail!("invalid device id: {}", id);
+    }
+
     let client = RestClient::new(env).await?;
     let req = TransferUserPlaybackRequest {
         device_ids: vec![id.into()],
@@ -0,0 +1,68 @@
+use super::*;
+use model::{CurrentlyPlayingContext, TrackOrEpisode};
+
+fn library_path(item_type: ItemType) -> anyhow::Result<&'static str> {
+    match item_type {
+        ItemType::Track => Ok("/v1/me/tracks"),
+        ItemType::Episode => Ok("/v1/me/episodes"),
+        t => anyhow::bail!("{} could not be saved to library.", t.as_str()),
+    }
+}
+
+/// Resolve `uri` or currently playing item.
+async fn target(
+    client: &RestClient,
+    uri: Option<&SpotifyId>,
+) -> anyhow::Result<(ItemType, String)> {
+    if let Some(uri) = uri {
+        return Ok((uri.item_type_or(ItemType::Track), uri.id.clone()));
+    }
+
+    let current_playing = client
+        .request::<_, MayBeEmpty<CurrentlyPlayingContext>>(PLAYBACK_STATE, Method::Get, Empty)
+        .await?;
+    match current_playing {
+        MayBeEmpty::Present(CurrentlyPlayingContext {
+            item: Some(item), ..
+        }) => item_id(&item),
+        _ => anyhow::bail!("currently not playing."),
+    }
+}
+
+fn item_id(item: &TrackOrEpisode) -> anyhow::Result<(ItemType, String)> {
+    match item {
+        TrackOrEpisode::Track { inner } => Ok((ItemType::Track, inner.id.clone())),
+        TrackOrEpisode::Episode { inner } => Ok((ItemType::Episode, inner.id.clone())),
+        TrackOrEpisode::Local { .. } => anyhow::bail!("local file could not be saved to library."),
+    }
+}
+
+pub async fn like(env: &Environment, uri: Option<&SpotifyId>) -> anyhow::Result<()> {
+    let client = RestClient::new(env).await?;
+    client.require_scopes(&["user-library-modify"]).await?;
+    let (item_type, id) = target(&client, uri).await?;
+    let url = format!("{}?ids={}", library_path(item_type)?, id);
+    client.request::<_, Empty>(&url, Method::Put, Empty).await?;
+    Ok(())
+}
+
+pub async fn unlike(env: &Environment, uri: Option<&SpotifyId>) -> anyhow::Result<()> {
+    let client = RestClient::new(env).await?;
+    client.require_scopes(&["user-library-modify"]).await?;
+    let (item_type, id) = target(&client, uri).await?;
+    let url = format!("{}?ids={}", library_path(item_type)?, id);
+    client
+        .request::<_, Empty>(&url, Method::Delete, Empty)
+        .await?;
+    Ok(())
+}
+
+/// Check if `item` is saved in library.
+pub async fn is_liked(client: &RestClient, item: &TrackOrEpisode) -> anyhow::Result<bool> {
+    let (item_type, id) = item_id(item)?;
+    let url = format!("{}/contains?ids={}", library_path(item_type)?, id);
+    let contains = client
+        .request::<_, Vec<bool>>(&url, Method::Get, Empty)
+        .await?;
+    Ok(contains.first().copied().unwrap_or_default())
+}
@@ -1,6 +1,18 @@
 pub mod agent;
+pub mod art;
+pub mod auth;
 pub mod device;
+pub mod library;
 pub mod open;
+pub mod queue;
+pub mod search;
+pub mod status;
 pub mod track;
 
+#[cfg(test)]
+mod tests;
+
 use super::*;
+
+/// Playback state. `item` is null for episodes unless requested by `additional_types`.
+const PLAYBACK_STATE: &str = "/v1/me/player?additional_types=episode";
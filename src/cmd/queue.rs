@@ -0,0 +1,36 @@
+use reqwest::Url;
+
+use super::*;
+
+pub async fn list(env: &Environment) -> anyhow::Result<()> {
+    let queue = RestClient::new(env)
+        .await?
+        .request::<_, model::Queue>("/v1/me/player/queue", Method::Get, Empty)
+        .await?;
+    for item in queue.queue {
+        env.output.print(&item)?;
+    }
+    Ok(())
+}
+
+pub async fn add(env: &Environment, uris: &[SpotifyId]) -> anyhow::Result<()> {
+    for uri in uris {
+        if !matches!(
+            uri.item_type_or(ItemType::Track),
+            ItemType::Track | ItemType::Episode
+        ) {
+            anyhow::bail!("only track or episode can be added to queue: {}", uri);
+        }
+    }
+
+    let client = RestClient::new(env).await?;
+    for uri in uris {
+        let mut url = Url::parse(&env.api_endpoint)?.join("/v1/me/player/queue")?;
+        url.query_pairs_mut()
+            .append_pair("uri", &uri.to_uri(ItemType::Track));
+        client
+            .request::<_, Empty>(url.as_str(), Method::Post, Empty)
+            .await?;
+    }
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use clap::ValueEnum;
+use reqwest::Url;
+
+use super::*;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum SearchType {
+    Track,
+    Album,
+    Artist,
+    Playlist,
+    Show,
+    Episode,
+}
+
+impl SearchType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Track => "track",
+            Self::Album => "album",
+            Self::Artist => "artist",
+            Self::Playlist => "playlist",
+            Self::Show => "show",
+            Self::Episode => "episode",
+        }
+    }
+}
+
+pub async fn search(
+    env: &Environment,
+    query: &str,
+    types: &[SearchType],
+    limit: u8,
+) -> anyhow::Result<()> {
+    let types = types
+        .iter()
+        .map(SearchType::as_str)
+        .collect::<Vec<_>>()
+        .join(",");
+    let mut url = Url::parse(&env.api_endpoint)?.join("/v1/search")?;
+    url.query_pairs_mut()
+        .append_pair("q", query)
+        .append_pair("type", &types)
+        .append_pair("limit", &limit.to_string());
+
+    let result = RestClient::new(env)
+        .await?
+        .request::<_, model::SearchResult>(url.as_str(), Method::Get, Empty)
+        .await?;
+
+    for track in result.tracks.into_iter().flat_map(|p| p.items) {
+        env.output.print(&track)?;
+    }
+    for album in result.albums.into_iter().flat_map(|p| p.items) {
+        env.output.print(&album)?;
+    }
+    for artist in result.artists.into_iter().flat_map(|p| p.items) {
+        env.output.print(&artist)?;
+    }
+    for playlist in result.playlists.into_iter().flat_map(|p| p.items).flatten() {
+        env.output.print(&playlist)?;
+    }
+    for show in result.shows.into_iter().flat_map(|p| p.items).flatten() {
+        env.output.print(&show)?;
+    }
+    for episode in result.episodes.into_iter().flat_map(|p| p.items).flatten() {
+        env.output.print(&episode)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use serde_json::Value;
+
+use super::*;
+use crate::output::join_names;
+use model::{CurrentlyPlayingContext, TrackOrEpisode};
+
+const PROGRESS_BAR_WIDTH: usize = 30;
+
+struct Status<'a> {
+    ctx: &'a CurrentlyPlayingContext,
+    item: &'a TrackOrEpisode,
+    /// `None` if unknown.
+    liked: Option<bool>,
+}
+
+impl Status<'_> {
+    fn duration_ms(&self) -> u64 {
+        match self.item {
+            TrackOrEpisode::Track { inner } => inner.duration_ms,
+            TrackOrEpisode::Episode { inner } => inner.duration_ms,
+            TrackOrEpisode::Local { inner } => inner.duration_ms,
+        }
+    }
+
+    fn progress_ms(&self) -> u64 {
+        self.ctx.progress_ms.unwrap_or_default()
+    }
+
+    fn state(&self) -> &'static str {
+        if self.ctx.is_playing {
+            "playing"
+        } else {
+            "paused"
+        }
+    }
+
+    fn shuffle(&self) -> &'static str {
+        if self.ctx.shuffle_state {
+            "on"
+        } else {
+            "off"
+        }
+    }
+}
+
+impl Record for Status<'_> {
+    fn fields(&self) -> Vec<(&'static str, Value)> {
+        let mut fields = vec![("state", self.state().into())];
+        fields.extend(self.item.fields());
+        fields.extend([
+            ("progress_ms", self.progress_ms().into()),
+            ("progress", position::format(self.progress_ms()).into()),
+            ("duration", position::format(self.duration_ms()).into()),
+            ("device", self.ctx.device.name.clone().into()),
+            ("volume_percent", self.ctx.device.volume_percent.into()),
+            ("shuffle", self.shuffle().into()),
+            ("repeat", self.ctx.repeat_state.clone().into()),
+            ("liked", self.liked.into()),
+        ]);
+        fields
+    }
+
+    fn text(&self) -> String {
+        let mut lines = vec![];
+        match self.item {
+            TrackOrEpisode::Track { inner } => {
+                lines.push(format!("track:    {}", inner.name));
+                lines.push(format!(
+                    "artists:  {}",
+                    join_names(inner.artists.iter().map(|a| &a.name), " ")
+                ));
+                if let Some(album) = &inner.album {
+                    lines.push(format!("album:    {}", album.name));
+                }
+            }
+            TrackOrEpisode::Episode { inner } => {
+                lines.push(format!("episode:  {}", inner.name));
+                lines.push(format!("show:     {}", inner.show.name));
+            }
+            TrackOrEpisode::Local { inner } => {
+                lines.push(format!("local:    {}", inner.name));
+                lines.push(format!(
+                    "artists:  {}",
+                    join_names(inner.artist_names(), " ")
+                ));
+                if let Some(album) = inner.album_name() {
+                    lines.push(format!("album:    {}", album));
+                }
+            }
+        }
+        lines.push(format!(
+            "progress: {} {} / {}",
+            progress_bar(self.progress_ms(), self.duration_ms()),
+            position::format(self.progress_ms()),
+            position::format(self.duration_ms())
+        ));
+        lines.push(format!("state:    {}", self.state()));
+        lines.push(format!("device:   {}", self.ctx.device.name));
+        if let Some(volume) = self.ctx.device.volume_percent {
+            lines.push(format!("volume:   {}%", volume));
+        }
+        lines.push(format!("shuffle:  {}", self.shuffle()));
+        lines.push(format!("repeat:   {}", self.ctx.repeat_state));
+        if let Some(liked) = self.liked {
+            lines.push(format!("liked:    {}", if liked { "♥" } else { "-" }));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Print current playback. Returns `false` if nothing is playing.
+pub async fn status(env: &Environment) -> anyhow::Result<bool> {
+    let client = RestClient::new(env).await?;
+    let current_playing = client
+        .request::<_, MayBeEmpty<CurrentlyPlayingContext>>(PLAYBACK_STATE, Method::Get, Empty)
+        .await?;
+    let ctx = if let MayBeEmpty::Present(ctx) = current_playing {
+        ctx
+    } else {
+        return Ok(false);
+    };
+    let item = if let Some(item) = &ctx.item {
+        item
+    } else {
+        return Ok(false);
+    };
+
+    let liked = match super::library::is_liked(&client, item).await {
+        Ok(liked) => Some(liked),
+        Err(err) => {
+            log::debug!("could not check library: {}", err);
+            None
+        }
+    };
+
+    env.output.print(&Status {
+        ctx: &ctx,
+        item,
+        liked,
+    })?;
+    Ok(true)
+}
+
+fn progress_bar(progress_ms: u64, duration_ms: u64) -> String {
+    let filled = (progress_ms.min(duration_ms) * PROGRESS_BAR_WIDTH as u64)
+        .checked_div(duration_ms)
+        .unwrap_or_default() as usize;
+    format!(
+        "[{}{}]",
+        "#".repeat(filled),
+        "-".repeat(PROGRESS_BAR_WIDTH - filled)
+    )
+}
@@ -0,0 +1,512 @@
+use crate::testing::{self, FakeSpotify, DEVICE_ID, PLAYLIST_ID, TRACK_ID};
+
+use super::*;
+
+#[tokio::test]
+async fn device_set_transfers_to_matched_device() {
+    let fake = FakeSpotify::start().await;
+    device::set(&fake.env, "kit", true).await.unwrap();
+
+    let commands = fake.commands();
+    assert_eq!(commands.len(), 1);
+    assert_eq!(commands[0].method, "PUT");
+    assert_eq!(commands[0].uri, "/v1/me/player");
+    assert_eq!(
+        commands[0].json(),
+        serde_json::json!({ "device_ids": ["a1b2c3"], "play": true })
+    );
+}
+
+#[tokio::test]
+async fn device_set_fails_without_match() {
+    let fake = FakeSpotify::start().await;
+    assert!(device::set(&fake.env, "bedroom", false).await.is_err());
+    assert!(fake.commands().is_empty());
+}
+
+#[tokio::test]
+async fn device_set_by_id_rejects_invalid_id() {
+    let fake = FakeSpotify::start().await;
+    assert!(device::set_by_id(&fake.env, "../x", false).await.is_err());
+    device::set_by_id(&fake.env, DEVICE_ID, false)
+        .await
+        .unwrap();
+    assert_eq!(fake.commands().len(), 1);
+}
+
+#[tokio::test]
+async fn list_follows_pages() {
+    let fake = FakeSpotify::start().await;
+    track::list(&fake.env, None).await.unwrap();
+    let uris = fake
+        .requests()
+        .into_iter()
+        .map(|r| r.uri)
+        .collect::<Vec<_>>();
+    assert!(uris.contains(&format!("/v1/playlists/{}/tracks?offset=2", PLAYLIST_ID)));
+}
+
+#[tokio::test]
+async fn list_stops_at_limit() {
+    let fake = FakeSpotify::start().await;
+    track::list(&fake.env, Some(2)).await.unwrap();
+    assert!(!fake.requests().iter().any(|r| r.uri.contains("offset=2")));
+}
+
+#[tokio::test]
+async fn next_and_prev() {
+    let fake = FakeSpotify::start().await;
+    track::next(&fake.env).await.unwrap();
+    track::prev(&fake.env).await.unwrap();
+    let commands = fake
+        .commands()
+        .into_iter()
+        .map(|r| (r.method, r.uri))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        commands,
+        vec![
+            ("POST".into(), "/v1/me/player/next".into()),
+            ("POST".into(), "/v1/me/player/previous".into()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn play_track_and_context() {
+    let fake = FakeSpotify::start().await;
+    let track = TRACK_ID.parse::<SpotifyId>().unwrap();
+    track::play(&fake.env, Some(&track), None, None, Some(1000))
+        .await
+        .unwrap();
+    let context = format!("spotify:playlist:{}", PLAYLIST_ID)
+        .parse::<SpotifyId>()
+        .unwrap();
+    track::play(
+        &fake.env,
+        None,
+        Some(&context),
+        Some(model::Offset::Position { position: 3 }),
+        None,
+    )
+    .await
+    .unwrap();
+
+    let album = "spotify:album:6akEvsycLGftJxYudPjmqK"
+        .parse::<SpotifyId>()
+        .unwrap();
+    track::play(
+        &fake.env,
+        Some(&album),
+        None,
+        Some(model::Offset::Position { position: 4 }),
+        None,
+    )
+    .await
+    .unwrap();
+    let err = track::play(
+        &fake.env,
+        Some(&track),
+        None,
+        Some(model::Offset::Position { position: 4 }),
+        None,
+    )
+    .await
+    .unwrap_err();
+    assert!(err.to_string().contains("offset requires"));
+
+    let commands = fake.commands();
+    assert_eq!(commands.len(), 3);
+    assert_eq!(commands[0].uri, "/v1/me/player/play");
+    assert_eq!(
+        commands[0].json(),
+        serde_json::json!({
+            "uris": [format!("spotify:track:{}", TRACK_ID)],
+            "position_ms": 1000,
+        })
+    );
+    assert_eq!(
+        commands[1].json(),
+        serde_json::json!({
+            "context_uri": format!("spotify:playlist:{}", PLAYLIST_ID),
+            "offset": { "position": 3 },
+        })
+    );
+    assert_eq!(
+        commands[2].json(),
+        serde_json::json!({
+            "context_uri": "spotify:album:6akEvsycLGftJxYudPjmqK",
+            "offset": { "position": 4 },
+        })
+    );
+}
+
+#[tokio::test]
+async fn seek_volume_shuffle_repeat() {
+    let fake = FakeSpotify::start().await;
+    track::seek(&fake.env, track::parse_seek("+10s").unwrap())
+        .await
+        .unwrap();
+    track::volume(&fake.env, track::parse_volume("-5").unwrap())
+        .await
+        .unwrap();
+    track::shuffle(&fake.env, track::Shuffle::Toggle)
+        .await
+        .unwrap();
+    track::repeat(&fake.env, track::Repeat::Track)
+        .await
+        .unwrap();
+
+    let uris = fake
+        .commands()
+        .into_iter()
+        .map(|r| r.uri)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        uris,
+        vec![
+            "/v1/me/player/seek?position_ms=70000",
+            "/v1/me/player/volume?volume_percent=45",
+            "/v1/me/player/shuffle?state=true",
+            "/v1/me/player/repeat?state=track",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn toggle_pauses_playing() {
+    let fake = FakeSpotify::start().await;
+    track::toggle(&fake.env).await.unwrap();
+    assert_eq!(fake.commands()[0].uri, "/v1/me/player/pause");
+}
+
+#[tokio::test]
+async fn player_error_reason_is_parsed() {
+    let fake = FakeSpotify::start().await;
+    fake.route(
+        "POST",
+        "/v1/me/player/next",
+        404,
+        r#"{"error":{"status":404,"message":"Player command failed: No active device found","reason":"NO_ACTIVE_DEVICE"}}"#,
+    );
+    let err = track::next(&fake.env).await.unwrap_err();
+    assert!(ApiError::is_player_error(
+        &err,
+        model::PlayerErrorReason::NoActiveDevice
+    ));
+}
+
+#[tokio::test]
+async fn unauthorized_refreshes_token() {
+    let fake = FakeSpotify::start().await;
+    fake.route("GET", "/v1/me/player/devices", 401, "");
+    assert!(device::list(&fake.env).await.is_err());
+
+    // Refreshed once, then gave up.
+    let requests = fake.requests();
+    assert_eq!(requests.iter().filter(|r| r.uri == "/api/token").count(), 1);
+    assert_eq!(
+        requests
+            .iter()
+            .filter(|r| r.uri == "/v1/me/player/devices")
+            .count(),
+        2
+    );
+}
+
+#[tokio::test]
+async fn queue_add() {
+    let fake = FakeSpotify::start().await;
+    let track = TRACK_ID.parse::<SpotifyId>().unwrap();
+    queue::add(&fake.env, &[track]).await.unwrap();
+    assert_eq!(
+        fake.commands()[0].uri,
+        format!("/v1/me/player/queue?uri=spotify%3Atrack%3A{}", TRACK_ID)
+    );
+
+    let album = "spotify:album:6akEvsycLGftJxYudPjmqK"
+        .parse::<SpotifyId>()
+        .unwrap();
+    assert!(queue::add(&fake.env, &[album]).await.is_err());
+    queue::list(&fake.env).await.unwrap();
+}
+
+#[tokio::test]
+async fn like_currently_playing() {
+    let fake = FakeSpotify::start().await;
+    library::like(&fake.env, None).await.unwrap();
+    library::unlike(&fake.env, None).await.unwrap();
+    let commands = fake
+        .commands()
+        .into_iter()
+        .map(|r| (r.method, r.uri))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        commands,
+        vec![
+            ("PUT".into(), format!("/v1/me/tracks?ids={}", TRACK_ID)),
+            ("DELETE".into(), format!("/v1/me/tracks?ids={}", TRACK_ID)),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn status_reports_nothing_playing() {
+    let fake = FakeSpotify::start().await;
+    assert!(status::status(&fake.env).await.unwrap());
+
+    fake.route("GET", "/v1/me/player", 204, "");
+    assert!(!status::status(&fake.env).await.unwrap());
+}
+
+#[tokio::test]
+async fn search_sends_query() {
+    let fake = FakeSpotify::start().await;
+    fake.route(
+        "GET",
+        "/v1/search",
+        200,
+        serde_json::json!({
+            "tracks": testing::paging(vec![testing::track(TRACK_ID, "Found")], None),
+        }),
+    );
+    search::search(&fake.env, "foo bar", &[search::SearchType::Track], 5)
+        .await
+        .unwrap();
+    assert_eq!(
+        fake.requests()[0].uri,
+        "/v1/search?q=foo+bar&type=track&limit=5"
+    );
+}
+
+#[tokio::test]
+async fn auth_status_and_logout() {
+    let fake = FakeSpotify::start().await;
+    auth::status(&fake.env).await.unwrap();
+
+    auth::logout(&fake.env).await.unwrap();
+    assert!(!fake.env.token_cache_path().exists());
+    assert!(auth::status(&fake.env).await.is_err());
+}
+
+#[tokio::test]
+async fn art_downloads_preferred_size() {
+    let fake = FakeSpotify::start().await;
+    let mut playing = testing::playing(&fake.url(&format!("/v1/playlists/{}", PLAYLIST_ID)));
+    playing["item"]["album"]["images"] = serde_json::json!([
+        { "url": fake.url("/image/large"), "width": 640, "height": 640 },
+        { "url": fake.url("/image/medium"), "width": 300, "height": 300 },
+        { "url": fake.url("/image/small"), "width": 64, "height": 64 },
+    ]);
+    fake.route("GET", "/v1/me/player", 200, playing);
+    fake.route("GET", "/image/medium", 200, "medium");
+
+    let dir = tempfile::tempdir().unwrap();
+    let out = dir.path().join("cover.jpg");
+    art::art(&fake.env, Some(200), Some(&out), None)
+        .await
+        .unwrap();
+    assert_eq!(std::fs::read_to_string(&out).unwrap(), "medium");
+    assert!(fake.env.art_cache_dir().join("medium.jpg").exists());
+
+    // Cached.
+    art::art(&fake.env, Some(200), None, None).await.unwrap();
+    let downloads = fake
+        .requests()
+        .iter()
+        .filter(|r| r.uri.starts_with("/image/"))
+        .count();
+    assert_eq!(downloads, 1);
+}
+
+#[tokio::test]
+async fn player_requests_episodes() {
+    let fake = FakeSpotify::start().await;
+    status::status(&fake.env).await.unwrap();
+    assert_eq!(
+        fake.requests()[0].uri,
+        "/v1/me/player?additional_types=episode"
+    );
+}
+
+#[tokio::test]
+async fn list_playlist_with_episode() {
+    let fake = FakeSpotify::start().await;
+    let mut episode = testing::episode("5555555555555555555555", "Episode");
+    episode["show"] = testing::show("6666666666666666666666");
+    let page = testing::paging(vec![serde_json::json!({ "track": episode })], None);
+    fake.route(
+        "GET",
+        &format!("/v1/playlists/{}/tracks", PLAYLIST_ID),
+        200,
+        page.clone(),
+    );
+    track::list(&fake.env, None).await.unwrap();
+    assert!(fake
+        .requests()
+        .iter()
+        .any(|r| r.uri.contains("/tracks?additional_types=track,episode")));
+
+    let page: model::Paging<model::PlaylistTrack> = serde_json::from_value(page).unwrap();
+    let episode = &page.items[0];
+    assert!(episode.text().ends_with("Episode (Show) [resume at 1:23]"));
+    let fields = episode.fields();
+    assert!(fields.contains(&("type", "episode".into())));
+    assert!(fields.contains(&("resume_position_ms", 83000.into())));
+    assert!(fields.contains(&("fully_played", false.into())));
+}
+
+#[tokio::test]
+async fn list_show_episodes() {
+    let fake = FakeSpotify::start().await;
+    let show_id = "6666666666666666666666";
+    let mut playing = testing::playing(&fake.url(&format!("/v1/shows/{}", show_id)));
+    playing["context"]["type"] = "show".into();
+    playing["context"]["uri"] = format!("spotify:show:{}", show_id).into();
+    fake.route("GET", "/v1/me/player", 200, playing);
+    let mut played = testing::episode("7777777777777777777777", "Second");
+    played["resume_point"]["fully_played"] = true.into();
+    let page = testing::paging(
+        vec![testing::episode("5555555555555555555555", "First"), played],
+        None,
+    );
+    fake.route(
+        "GET",
+        &format!("/v1/shows/{}/episodes", show_id),
+        200,
+        page.clone(),
+    );
+    track::list(&fake.env, None).await.unwrap();
+    assert!(fake
+        .requests()
+        .iter()
+        .any(|r| r.uri == format!("/v1/shows/{}/episodes", show_id)));
+
+    let page: model::Paging<model::SimplifiedEpisode> = serde_json::from_value(page).unwrap();
+    assert!(page.items[0]
+        .text()
+        .ends_with("First (2022-01-01) [resume at 1:23]"));
+    assert!(page.items[0]
+        .fields()
+        .contains(&("resume_position_ms", 83000.into())));
+    assert!(page.items[1]
+        .text()
+        .ends_with("Second (2022-01-01) [played]"));
+    assert!(page.items[1]
+        .fields()
+        .contains(&("fully_played", true.into())));
+}
+
+#[tokio::test]
+async fn list_playlist_with_local_and_removed_items() {
+    let fake = FakeSpotify::start().await;
+    let mut unplayable = testing::track("8888888888888888888888", "Gone");
+    unplayable["is_playable"] = false.into();
+    let page = testing::paging(
+        vec![
+            serde_json::json!({ "is_local": true, "track": testing::local_track("Local Song") }),
+            serde_json::json!({ "track": null }),
+            serde_json::json!({ "track": unplayable }),
+        ],
+        None,
+    );
+    fake.route(
+        "GET",
+        &format!("/v1/playlists/{}/tracks", PLAYLIST_ID),
+        200,
+        page.clone(),
+    );
+    track::list(&fake.env, None).await.unwrap();
+    assert!(fake
+        .requests()
+        .iter()
+        .any(|r| r.uri.contains("/tracks?") && r.uri.contains("market=from_token")));
+
+    let page: model::Paging<model::PlaylistTrack> = serde_json::from_value(page).unwrap();
+    let field = |record: &model::PlaylistTrack, key: &str| {
+        record
+            .fields()
+            .into_iter()
+            .find(|(k, _)| *k == key)
+            .unwrap()
+            .1
+    };
+    let local = &page.items[0];
+    assert!(local.text().ends_with("Local Song (Local Artist) [local]"));
+    assert_eq!(field(local, "type"), "local");
+    assert_eq!(field(local, "album"), "Local Album");
+    assert_eq!(field(local, "is_playable"), false);
+
+    let removed = &page.items[1];
+    assert_eq!(removed.text(), "- [unavailable]");
+    assert_eq!(field(removed, "type"), "unavailable");
+    assert_eq!(field(removed, "is_playable"), false);
+
+    let unplayable = &page.items[2];
+    assert!(unplayable.text().ends_with("[unavailable]"));
+    assert_eq!(field(unplayable, "type"), "track");
+    assert_eq!(field(unplayable, "is_playable"), false);
+}
+
+#[tokio::test]
+async fn local_file_in_player_and_queue() {
+    let fake = FakeSpotify::start().await;
+    let mut playing = testing::playing(&fake.url(&format!("/v1/playlists/{}", PLAYLIST_ID)));
+    playing["item"] = testing::local_track("Local Song");
+    fake.route("GET", "/v1/me/player", 200, playing);
+    fake.route(
+        "GET",
+        "/v1/me/player/queue",
+        200,
+        serde_json::json!({
+            "currently_playing": testing::local_track("Local Song"),
+            "queue": [testing::local_track("Next Song"), testing::track(TRACK_ID, "Next")],
+        }),
+    );
+
+    assert!(status::status(&fake.env).await.unwrap());
+    queue::list(&fake.env).await.unwrap();
+    track::list(&fake.env, None).await.unwrap();
+    let err = library::like(&fake.env, None).await.unwrap_err();
+    assert_eq!(err.to_string(), "local file could not be saved to library.");
+    let err = art::art(&fake.env, Some(200), None, None)
+        .await
+        .unwrap_err();
+    assert_eq!(err.to_string(), "no cover art for current item.");
+    assert!(fake.commands().is_empty());
+}
+
+#[tokio::test]
+async fn auth_status_never_logs_in() {
+    let fake = FakeSpotify::start().await;
+    fake.login(-10);
+    let path = fake.env.token_cache_path();
+    let mut token =
+        serde_json::from_slice::<serde_json::Value>(&std::fs::read(&path).unwrap()).unwrap();
+    token["scope"] = "streaming".into();
+    std::fs::write(&path, token.to_string()).unwrap();
+
+    // Login fails in tests because nothing is pasted.
+    auth::status(&fake.env).await.unwrap();
+    assert!(fake.requests().iter().any(|r| r.uri == "/api/token"));
+
+    let info = crate::auth::token_info(&fake.env).await.unwrap().unwrap();
+    assert!(info
+        .missing_scopes
+        .contains(&"user-library-modify".to_string()));
+    assert!(info.expires_in().is_some());
+}
+
+#[tokio::test]
+async fn list_logs_in_if_playback_position_scope_is_missing() {
+    let fake = FakeSpotify::start().await;
+    let path = fake.env.token_cache_path();
+    let mut token =
+        serde_json::from_slice::<serde_json::Value>(&std::fs::read(&path).unwrap()).unwrap();
+    token["scope"] = "streaming user-read-playback-state".into();
+    std::fs::write(&path, token.to_string()).unwrap();
+
+    let err = track::list(&fake.env, None).await.unwrap_err();
+    assert_eq!(err.to_string(), "no code pasted.");
+    assert!(!fake.requests().iter().any(|r| r.uri.contains("/tracks")));
+}
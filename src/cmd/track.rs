@@ -1,10 +1,13 @@
+use clap::ValueEnum;
+use futures::TryStreamExt;
+
 use super::*;
 
-pub async fn list(env: &Environment) -> anyhow::Result<()> {
+pub async fn list(env: &Environment, limit: Option<usize>) -> anyhow::Result<()> {
     let client = RestClient::new(env).await?;
     let current_playing = client
         .request::<_, MayBeEmpty<model::CurrentlyPlayingContext>>(
-            "/v1/me/player",
+            PLAYBACK_STATE,
             Method::Get,
             Empty,
         )
@@ -28,67 +31,44 @@ pub async fn list(env: &Environment) -> anyhow::Result<()> {
                 let tracks = client
                     .request::<_, model::Tracks>(&url, Method::Get, Empty)
                     .await?;
-                for track in tracks.tracks {
-                    println!(
-                        "{} {} ({})",
-                        track.uri,
-                        track.name,
-                        track
-                            .artists
-                            .iter()
-                            .map(|a| a.name.clone())
-                            .collect::<Vec<_>>()
-                            .join(" ")
-                    );
+                for track in tracks.tracks.into_iter().take(limit.unwrap_or(usize::MAX)) {
+                    env.output.print(&track)?;
                 }
             }
             "playlist" => {
-                let url = format!("{}/tracks?additional_types=track", &ctx.href);
-                let playlist = client
-                    .request::<_, model::Paging<model::PlaylistTrack>>(&url, Method::Get, Empty)
+                // Resume points of episodes.
+                client
+                    .require_scopes(&["user-read-playback-position"])
                     .await?;
-                for track in playlist.items {
-                    match track.track {
-                        Some(model::TrackOrEpisode::Track { inner }) => {
-                            println!(
-                                "{} {} ({})",
-                                inner.uri,
-                                inner.name,
-                                inner
-                                    .artists
-                                    .iter()
-                                    .map(|a| a.name.clone())
-                                    .collect::<Vec<_>>()
-                                    .join(" ")
-                            );
-                        }
-                        Some(model::TrackOrEpisode::Episode { .. }) => {
-                            todo!();
-                        }
-                        None => todo!(),
-                    }
+                let url = format!(
+                    "{}/tracks?additional_types=track,episode&market=from_token",
+                    &ctx.href
+                );
+                let tracks = client.paginate::<model::PlaylistTrack>(&url, limit);
+                tokio::pin!(tracks);
+                while let Some(track) = tracks.try_next().await? {
+                    env.output.print(&track)?;
                 }
             }
             "album" => {
-                let url = format!("{}/tracks", &ctx.href);
-                let tracks = client
-                    .request::<_, model::Paging<model::Track>>(&url, Method::Get, Empty)
+                let url = format!("{}/tracks?market=from_token", &ctx.href);
+                let tracks = client.paginate::<model::Track>(&url, limit);
+                tokio::pin!(tracks);
+                while let Some(track) = tracks.try_next().await? {
+                    env.output.print(&track)?;
+                }
+            }
+            "show" => {
+                client
+                    .require_scopes(&["user-read-playback-position"])
                     .await?;
-                for track in tracks.items {
-                    println!(
-                        "{} {} ({})",
-                        track.uri,
-                        track.name,
-                        track
-                            .artists
-                            .iter()
-                            .map(|a| a.name.clone())
-                            .collect::<Vec<_>>()
-                            .join(" ")
-                    );
+                let url = format!("{}/episodes", &ctx.href);
+                let episodes = client.paginate::<model::SimplifiedEpisode>(&url, limit);
+                tokio::pin!(episodes);
+                while let Some(episode) = episodes.try_next().await? {
+                    env.output.print(&episode)?;
                 }
             }
-            "show" => todo!(),
             s => anyhow::bail!("unknown context: {}", s),
         }
     } else {
@@ -113,11 +93,37 @@ pub async fn prev(env: &Environment) -> anyhow::Result<()> {
     Ok(())
 }
 
-pub async fn play(env: &Environment, uri: &str) -> anyhow::Result<()> {
-    let request = model::StartResumeAUsersPlaybackRequest {
-        uris: Some(vec![uri.into()]),
+pub async fn play(
+    env: &Environment,
+    uri: Option<&SpotifyId>,
+    context: Option<&SpotifyId>,
+    offset: Option<model::Offset>,
+    position_ms: Option<u64>,
+) -> anyhow::Result<()> {
+    let mut request = model::StartResumeAUsersPlaybackRequest {
+        offset,
+        position_ms,
         ..Default::default()
     };
+    if let Some(uri) = uri {
+        match uri.item_type_or(ItemType::Track) {
+            ItemType::Track | ItemType::Episode => {
+                request.uris = Some(vec![uri.to_uri(ItemType::Track)])
+            }
+            t if t.is_context() => request.context_uri = Some(uri.to_uri(ItemType::Track)),
+            _ => anyhow::bail!("could not play: {}", uri),
+        }
+    }
+    if let Some(context) = context {
+        match context.item_type {
+            Some(t) if t.is_context() => request.context_uri = Some(context.to_string()),
+            Some(_) => anyhow::bail!("not a context: {}", context),
+            None => anyhow::bail!("could not determine type of {}. use uri or url.", context),
+        }
+    }
+    if request.offset.is_some() && request.context_uri.is_none() {
+        anyhow::bail!("offset requires album, artist, playlist or show to play.");
+    }
     RestClient::new(env)
         .await?
         .request::<_, Empty>("/v1/me/player/play", Method::Put, request)
@@ -140,3 +146,184 @@ pub async fn stop(env: &Environment) -> anyhow::Result<()> {
         .await?;
     Ok(())
 }
+
+/// Absolute or relative amount. (e.g. `50`, `+5`, `-5`)
+#[derive(Debug, Clone, Copy)]
+pub enum Adjust {
+    To(u64),
+    Forward(u64),
+    Backward(u64),
+}
+
+impl Adjust {
+    fn parse<F>(s: &str, parse: F) -> anyhow::Result<Self>
+    where
+        F: Fn(&str) -> anyhow::Result<u64>,
+    {
+        if let Some(s) = s.strip_prefix('+') {
+            Ok(Self::Forward(parse(s)?))
+        } else if let Some(s) = s.strip_prefix('-') {
+            Ok(Self::Backward(parse(s)?))
+        } else {
+            Ok(Self::To(parse(s)?))
+        }
+    }
+
+    fn apply(self, current: u64, max: u64) -> u64 {
+        match self {
+            Self::To(v) => v,
+            Self::Forward(v) => current.saturating_add(v),
+            Self::Backward(v) => current.saturating_sub(v),
+        }
+        .min(max)
+    }
+}
+
+/// Parse seek position. (e.g. `+10s`, `-10s`, `1:23`)
+pub fn parse_seek(s: &str) -> anyhow::Result<Adjust> {
+    Adjust::parse(s, position::parse)
+}
+
+/// Parse volume percent. (e.g. `50`, `+5`, `-5`)
+pub fn parse_volume(s: &str) -> anyhow::Result<Adjust> {
+    Adjust::parse(s, |s| match s.trim_end_matches('%').parse::<u64>() {
+        Ok(v) if v <= 100 => Ok(v),
+        _ => anyhow::bail!("invalid volume: {}", s),
+    })
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Shuffle {
+    On,
+    Off,
+    Toggle,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Repeat {
+    Off,
+    Track,
+    Context,
+}
+
+impl Repeat {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Track => "track",
+            Self::Context => "context",
+        }
+    }
+}
+
+async fn current_playing(client: &RestClient) -> anyhow::Result<model::CurrentlyPlayingContext> {
+    let current_playing = client
+        .request::<_, MayBeEmpty<model::CurrentlyPlayingContext>>(
+            PLAYBACK_STATE,
+            Method::Get,
+            Empty,
+        )
+        .await?;
+    if let MayBeEmpty::Present(p) = current_playing {
+        Ok(p)
+    } else {
+        anyhow::bail!("currently not playing.");
+    }
+}
+
+fn ensure_allowed(disallowed: Option<bool>, action: &str) -> anyhow::Result<()> {
+    if disallowed == Some(true) {
+        anyhow::bail!("{} is not allowed for current playback.", action);
+    }
+    Ok(())
+}
+
+pub async fn seek(env: &Environment, position: Adjust) -> anyhow::Result<()> {
+    let client = RestClient::new(env).await?;
+    let current_playing = current_playing(&client).await?;
+    ensure_allowed(current_playing.actions.seeking, "seeking")?;
+
+    let duration_ms = match &current_playing.item {
+        Some(model::TrackOrEpisode::Track { inner }) => inner.duration_ms,
+        Some(model::TrackOrEpisode::Episode { inner }) => inner.duration_ms,
+        Some(model::TrackOrEpisode::Local { inner }) => inner.duration_ms,
+        None => u64::MAX,
+    };
+    let position_ms = position.apply(current_playing.progress_ms.unwrap_or_default(), duration_ms);
+    let url = format!("/v1/me/player/seek?position_ms={}", position_ms);
+    client.request::<_, Empty>(&url, Method::Put, Empty).await?;
+    Ok(())
+}
+
+pub async fn volume(env: &Environment, volume: Adjust) -> anyhow::Result<()> {
+    let client = RestClient::new(env).await?;
+    let current_playing = current_playing(&client).await?;
+    let current = match current_playing.device.volume_percent {
+        Some(v) if !current_playing.device.is_restricted => v,
+        _ => anyhow::bail!("volume control is not allowed for current device."),
+    };
+
+    let volume_percent = volume.apply(current.into(), 100);
+    let url = format!("/v1/me/player/volume?volume_percent={}", volume_percent);
+    client.request::<_, Empty>(&url, Method::Put, Empty).await?;
+    Ok(())
+}
+
+pub async fn shuffle(env: &Environment, shuffle: Shuffle) -> anyhow::Result<()> {
+    let client = RestClient::new(env).await?;
+    let current_playing = current_playing(&client).await?;
+    ensure_allowed(current_playing.actions.toggling_shuffle, "toggling shuffle")?;
+
+    let state = match shuffle {
+        Shuffle::On => true,
+        Shuffle::Off => false,
+        Shuffle::Toggle => !current_playing.shuffle_state,
+    };
+    let url = format!("/v1/me/player/shuffle?state={}", state);
+    client.request::<_, Empty>(&url, Method::Put, Empty).await?;
+    Ok(())
+}
+
+pub async fn repeat(env: &Environment, repeat: Repeat) -> anyhow::Result<()> {
+    let client = RestClient::new(env).await?;
+    let current_playing = current_playing(&client).await?;
+    let actions = &current_playing.actions;
+    match (repeat, current_playing.repeat_state.as_ref()) {
+        (Repeat::Track, _) | (Repeat::Off, "track") => {
+            ensure_allowed(actions.toggling_repeat_track, "toggling repeat track")?
+        }
+        (Repeat::Context, _) | (Repeat::Off, "context") => {
+            ensure_allowed(actions.toggling_repeat_context, "toggling repeat context")?
+        }
+        _ => {}
+    }
+
+    let url = format!("/v1/me/player/repeat?state={}", repeat.as_str());
+    client.request::<_, Empty>(&url, Method::Put, Empty).await?;
+    Ok(())
+}
+
+/// Pause if playing, otherwise resume.
+pub async fn toggle(env: &Environment) -> anyhow::Result<()> {
+    let client = RestClient::new(env).await?;
+    let current_playing = client
+        .request::<_, MayBeEmpty<model::CurrentlyPlayingContext>>(
+            PLAYBACK_STATE,
+            Method::Get,
+            Empty,
+        )
+        .await?;
+    let path = match current_playing {
+        MayBeEmpty::Present(p) if p.is_playing => {
+            ensure_allowed(p.actions.pausing, "pausing")?;
+            "/v1/me/player/pause"
+        }
+        MayBeEmpty::Present(p) => {
+            ensure_allowed(p.actions.resuming, "resuming")?;
+            "/v1/me/player/play"
+        }
+        MayBeEmpty::Empty => "/v1/me/player/play",
+    };
+    client.request::<_, Empty>(path, Method::Put, Empty).await?;
+    Ok(())
+}
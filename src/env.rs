@@ -1,22 +1,108 @@
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use tokio::fs;
 
 use crate::auth::AuthorizationConfig;
+use crate::output::OutputFormat;
+use crate::rest::RetryPolicy;
 
 const API_ENDPOINT: &str = "https://api.spotify.com";
 
-#[derive(Debug)]
+/// Content of `config.toml`.
+///
+/// Settings in `[profiles.<name>]` override top level settings when the profile is selected.
+#[derive(Debug, Deserialize)]
+struct Config {
+    #[serde(flatten)]
+    auth: AuthorizationConfig,
+    #[serde(default)]
+    retry: RetryPolicy,
+    #[serde(default = "Config::default_api_endpoint")]
+    api_endpoint: String,
+}
+
+impl Config {
+    fn default_api_endpoint() -> String {
+        API_ENDPOINT.into()
+    }
+
+    async fn load<P>(path: P, profile: Option<&str>) -> anyhow::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let buf = fs::read(path).await?;
+        let mut value = toml::from_slice::<toml::Value>(&buf)?;
+        let profiles = value
+            .as_table_mut()
+            .and_then(|t| t.remove("profiles"))
+            .unwrap_or_else(|| toml::Value::Table(Default::default()));
+        if let Some(profile) = profile {
+            let overlay = profiles
+                .get(profile)
+                .ok_or_else(|| anyhow::anyhow!("unknown profile: {}", profile))?;
+            merge(&mut value, overlay.clone());
+        }
+        Ok(value.try_into()?)
+    }
+}
+
+/// Merge `overlay` into `base`. Tables are merged recursively, other values are replaced.
+fn merge(base: &mut toml::Value, overlay: toml::Value) {
+    match (base, overlay) {
+        (toml::Value::Table(base), toml::Value::Table(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(base) => merge(base, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct Environment {
     pub auth_config: AuthorizationConfig,
     pub api_endpoint: String,
+    pub retry_policy: RetryPolicy,
+    pub output: OutputFormat,
+    pub cache_dir: PathBuf,
+    /// Selected profile. `None` for default.
+    pub profile: Option<String>,
 }
 
 impl Environment {
-    pub async fn load() -> anyhow::Result<Self> {
-        let auth_config = AuthorizationConfig::load(&Self::config_file()).await?;
+    /// Load config of `profile`. Falls back to `$SPTTY_PROFILE`.
+    pub async fn load(profile: Option<String>) -> anyhow::Result<Self> {
+        let profile = profile
+            .or_else(|| env::var("SPTTY_PROFILE").ok())
+            .filter(|p| !p.is_empty());
+        if let Some(profile) = &profile {
+            if !profile
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            {
+                anyhow::bail!("invalid profile name: {}", profile);
+            }
+        }
+
+        let config = Config::load(&Self::config_file(), profile.as_deref()).await?;
+        let mut cache_dir = dirs::cache_dir().unwrap().join("sptty/");
+        if let Some(profile) = &profile {
+            cache_dir = cache_dir.join("profiles").join(profile);
+        }
         Ok(Self {
-            auth_config,
-            api_endpoint: API_ENDPOINT.into(),
+            auth_config: config.auth,
+            api_endpoint: config.api_endpoint,
+            retry_policy: config.retry,
+            output: OutputFormat::default(),
+            cache_dir,
+            profile,
         })
     }
 
@@ -31,6 +117,26 @@ impl Environment {
         Self::config_dir().join("config.toml")
     }
 
+    pub fn token_cache_path(&self) -> PathBuf {
+        self.cache_dir.join("token")
+    }
+
+    pub fn audio_cache_dir(&self) -> PathBuf {
+        self.cache_dir.join("audio")
+    }
+
+    pub fn art_cache_dir(&self) -> PathBuf {
+        self.cache_dir.join("art")
+    }
+
+    /// Systemd user unit name of playback agent.
+    pub fn agent_unit_name(&self) -> String {
+        match &self.profile {
+            Some(profile) => format!("sptty-{}", profile),
+            None => "sptty".into(),
+        }
+    }
+
     pub fn systemd_user_runtime_dir() -> PathBuf {
         let dir = dirs::runtime_dir().expect("no $XDG_DATA_HOME found.");
         dir.join("systemd/user")
@@ -0,0 +1,70 @@
+//! Token storage in freedesktop Secret Service.
+//!
+//! Runs `secret-tool` of libsecret (`libsecret-tools` package) instead of talking D-Bus directly.
+//! Callers fall back to token file if it is missing or fails.
+use std::process::Stdio;
+
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+const SERVICE: &str = "sptty";
+#[cfg(not(test))]
+const SECRET_TOOL: &str = "secret-tool";
+/// Never touch real keyring in tests. This command does not exist.
+#[cfg(test)]
+const SECRET_TOOL: &str = "sptty-test-missing-secret-tool";
+
+/// Returns `None` if no secret is stored for `account`.
+pub async fn load(account: &str) -> anyhow::Result<Option<String>> {
+    let output = Command::new(SECRET_TOOL)
+        .args(["lookup", "service", SERVICE, "account", account])
+        .stdin(Stdio::null())
+        .output()
+        .await?;
+    if output.status.success() {
+        return Ok(Some(String::from_utf8(output.stdout)?));
+    }
+    if output.stderr.is_empty() {
+        return Ok(None);
+    }
+    anyhow::bail!(
+        "secret-tool lookup failed: {}",
+        String::from_utf8_lossy(&output.stderr).trim()
+    )
+}
+
+pub async fn store(account: &str, secret: &str) -> anyhow::Result<()> {
+    let label = format!("sptty token ({})", account);
+    let mut child = Command::new(SECRET_TOOL)
+        .args([
+            "store", "--label", &label, "service", SERVICE, "account", account,
+        ])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let mut stdin = child.stdin.take().unwrap();
+    stdin.write_all(secret.as_bytes()).await?;
+    drop(stdin);
+
+    let output = child.wait_with_output().await?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "secret-tool store failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+pub async fn clear(account: &str) -> anyhow::Result<()> {
+    let status = Command::new(SECRET_TOOL)
+        .args(["clear", "service", SERVICE, "account", account])
+        .stdin(Stdio::null())
+        .status()
+        .await?;
+    if !status.success() {
+        anyhow::bail!("secret-tool clear failed.");
+    }
+    Ok(())
+}
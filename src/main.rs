@@ -4,23 +4,50 @@ use clap::{crate_authors, crate_description, crate_version, AppSettings, IntoApp
 mod auth;
 mod cmd;
 mod env;
+#[cfg(feature = "keyring")]
+mod keyring;
 pub(crate) mod model;
+mod output;
 mod playback;
+mod position;
 mod rest;
+#[cfg(test)]
+mod testing;
+mod uri;
 
 pub(crate) use auth::get_token;
 pub(crate) use env::Environment;
-pub(crate) use playback::connect;
-pub(crate) use rest::{Empty, MayBeEmpty, Method, RestClient};
+pub(crate) use output::{OutputFormat, Record};
+pub(crate) use playback::{connect, DEVICE_NAME};
+pub(crate) use rest::{ApiError, Empty, MayBeEmpty, Method, RestClient};
+pub(crate) use uri::{ItemType, SpotifyId};
 
 #[derive(Debug, Parser)]
 #[clap(version = crate_version!(), author = crate_authors!(), about = crate_description!())]
 #[clap(setting = AppSettings::InferSubcommands)]
+#[cfg_attr(
+    feature = "keyring",
+    clap(
+        after_help = "Token is stored in Secret Service by `secret-tool` command of libsecret. Falls back to token file if it is not available."
+    )
+)]
 struct Cli {
     /// Login to spotify.
     #[clap(long)]
     login: bool,
 
+    /// Print authorization url and paste redirected url instead of opening browser.
+    #[clap(long, global = true)]
+    no_browser: bool,
+
+    /// Profile name. Selects `[profiles.<name>]` in config and separate caches. [env: SPTTY_PROFILE]
+    #[clap(long, global = true)]
+    profile: Option<String>,
+
+    /// Output format. text, json, tsv or template. (e.g. "{artist} - {name}")
+    #[clap(long, short, global = true, default_value = "text")]
+    output: OutputFormat,
+
     #[clap(subcommand)]
     subcommand: Option<SubCommands>,
 }
@@ -36,7 +63,11 @@ enum SubCommands {
     Device(Device),
     /// List current playing playlist.
     #[clap(display_order = 2)]
-    List,
+    List {
+        /// Maximum number of tracks to list.
+        #[clap(long, short)]
+        limit: Option<usize>,
+    },
     /// Skip next track current playing playlist.
     #[clap(display_order = 3)]
     NextTrack,
@@ -46,8 +77,20 @@ enum SubCommands {
     /// Play track current playing playlist.
     #[clap(display_order = 5)]
     Play {
-        /// Plaing track uri
-        track_uri: Option<String>,
+        /// Plaing track uri, url or id. Album, artist, playlist or show is played as context.
+        track_uri: Option<SpotifyId>,
+        /// Playing context uri or url. (album, artist, playlist or show)
+        #[clap(long, conflicts_with = "track-uri")]
+        context: Option<SpotifyId>,
+        /// Start from the item at this zero based index of context.
+        #[clap(long, conflicts_with = "offset-uri")]
+        offset: Option<u64>,
+        /// Start from the item of this uri in context. Bare id is episode in show, track otherwise.
+        #[clap(long)]
+        offset_uri: Option<SpotifyId>,
+        /// Start position. (e.g. 83, 1:23)
+        #[clap(long, value_parser = position::parse)]
+        position: Option<u64>,
     },
     /// Stop playing.
     #[clap(display_order = 6)]
@@ -55,6 +98,87 @@ enum SubCommands {
     /// Open spotify client.
     #[clap(display_order = 7)]
     Open,
+    /// Search tracks, albums, artists, playlists, shows and episodes.
+    #[clap(display_order = 8)]
+    Search {
+        /// Search query.
+        query: String,
+        /// Item types to search.
+        #[clap(
+            long = "type",
+            short,
+            value_enum,
+            value_delimiter = ',',
+            default_value = "track"
+        )]
+        types: Vec<cmd::search::SearchType>,
+        /// Maximum number of results for each type.
+        #[clap(long, short, value_parser = clap::value_parser!(u8).range(1..=50), default_value = "10")]
+        limit: u8,
+    },
+    /// Show current playback. Exit with 1 if nothing is playing.
+    #[clap(display_order = 9)]
+    Status,
+    /// Manage playback queue.
+    #[clap(display_order = 10)]
+    Queue(Queue),
+    /// Seek to position.
+    #[clap(display_order = 11)]
+    Seek {
+        /// Absolute or relative position. (e.g. 1:23, +10s, -10s)
+        #[clap(allow_hyphen_values = true, value_parser = cmd::track::parse_seek)]
+        position: cmd::track::Adjust,
+    },
+    /// Set volume.
+    #[clap(display_order = 12)]
+    Volume {
+        /// Absolute or relative volume percent. (e.g. 50, +5, -5)
+        #[clap(allow_hyphen_values = true, value_parser = cmd::track::parse_volume)]
+        volume: cmd::track::Adjust,
+    },
+    /// Set shuffle mode.
+    #[clap(display_order = 13)]
+    Shuffle {
+        #[clap(value_enum)]
+        state: cmd::track::Shuffle,
+    },
+    /// Pause if playing, otherwise resume.
+    #[clap(display_order = 6)]
+    Toggle,
+    /// Set repeat mode.
+    #[clap(display_order = 14)]
+    Repeat {
+        #[clap(value_enum)]
+        state: cmd::track::Repeat,
+    },
+    /// Save track or episode to library. (default: currently playing)
+    #[clap(display_order = 15)]
+    Like {
+        /// Track or episode uri, url or id.
+        uri: Option<SpotifyId>,
+    },
+    /// Remove track or episode from library. (default: currently playing)
+    #[clap(display_order = 16)]
+    Unlike {
+        /// Track or episode uri, url or id.
+        uri: Option<SpotifyId>,
+    },
+    /// Show or clear login state.
+    #[clap(display_order = 17)]
+    Auth(Auth),
+    /// Download cover art of currently playing item and print its path.
+    #[clap(display_order = 18)]
+    Art {
+        /// Preferred width in pixels. Smallest image not smaller than this is chosen.
+        #[clap(long, short)]
+        size: Option<u64>,
+        /// Copy image to this file.
+        #[clap(long)]
+        out: Option<std::path::PathBuf>,
+        /// Render image in terminal instead of printing path.
+        #[clap(long, value_enum, min_values = 0, default_missing_value = "auto")]
+        show: Option<cmd::art::Graphics>,
+    },
 }
 
 #[derive(Debug, Parser)]
@@ -85,6 +209,46 @@ enum DeviceSubCommands {
     },
 }
 
+#[derive(Debug, Parser)]
+#[clap(setting = AppSettings::InferSubcommands)]
+struct Queue {
+    #[clap(subcommand)]
+    subcommand: Option<QueueSubCommands>,
+}
+
+#[derive(Debug, Parser)]
+#[clap(setting = AppSettings::InferSubcommands)]
+enum QueueSubCommands {
+    /// List upcoming tracks and episodes. (default)
+    #[clap(display_order = 0)]
+    List,
+    /// Add tracks or episodes to the end of queue.
+    #[clap(display_order = 1)]
+    Add {
+        /// Track or episode uri, url or id.
+        #[clap(required = true)]
+        uris: Vec<SpotifyId>,
+    },
+}
+
+#[derive(Debug, Parser)]
+#[clap(setting = AppSettings::InferSubcommands)]
+struct Auth {
+    #[clap(subcommand)]
+    subcommand: Option<AuthSubCommands>,
+}
+
+#[derive(Debug, Parser)]
+#[clap(setting = AppSettings::InferSubcommands)]
+enum AuthSubCommands {
+    /// Show logged in user, granted scopes and token expiry. (default)
+    #[clap(display_order = 0)]
+    Status,
+    /// Remove cached token and audio cache.
+    #[clap(display_order = 1)]
+    Logout,
+}
+
 #[derive(Debug, Parser)]
 #[clap(setting = AppSettings::InferSubcommands)]
 struct Agent {
@@ -116,7 +280,11 @@ async fn main() -> anyhow::Result<()> {
 
     let opts = Cli::parse();
 
-    let env = Environment::load().await?;
+    let mut env = Environment::load(opts.profile).await?;
+    env.output = opts.output;
+    if opts.no_browser {
+        env.auth_config.no_browser = true;
+    }
 
     if opts.login {
         auth::authenticate(&env, default_leading_authorization_url).await?;
@@ -136,11 +304,11 @@ async fn main() -> anyhow::Result<()> {
 
         SubCommands::Agent(Agent {
             subcommand: None | Some(AgentSubCommands::Start),
-        }) => cmd::agent::start().await,
+        }) => cmd::agent::start(&env).await,
 
         SubCommands::Agent(Agent {
             subcommand: Some(AgentSubCommands::Kill),
-        }) => cmd::agent::kill().await,
+        }) => cmd::agent::kill(&env).await,
 
         SubCommands::Device(Device {
             subcommand: None | Some(DeviceSubCommands::List),
@@ -164,19 +332,103 @@ async fn main() -> anyhow::Result<()> {
                 }),
         }) => cmd::device::set_by_id(&env, &name, play).await,
 
-        SubCommands::List => cmd::track::list(&env).await,
-        SubCommands::Play { track_uri } => {
-            cmd::agent::start().await?;
-            if let Some(uri) = track_uri {
-                cmd::track::play(&env, &uri).await
-            } else {
-                cmd::track::resume(&env).await
+        SubCommands::List { limit } => cmd::track::list(&env, limit).await,
+        SubCommands::Play {
+            track_uri,
+            context,
+            offset,
+            offset_uri,
+            position,
+        } => {
+            let item_type = match context.as_ref().or(track_uri.as_ref()) {
+                Some(c) if c.item_type == Some(ItemType::Show) => ItemType::Episode,
+                _ => ItemType::Track,
+            };
+            let offset = offset
+                .map(|position| model::Offset::Position { position })
+                .or_else(|| {
+                    offset_uri.map(|uri| model::Offset::Uri {
+                        uri: uri.to_uri(item_type),
+                    })
+                });
+            let play = || async {
+                if track_uri.is_some() || context.is_some() || position.is_some() {
+                    cmd::track::play(
+                        &env,
+                        track_uri.as_ref(),
+                        context.as_ref(),
+                        offset.clone(),
+                        position,
+                    )
+                    .await
+                } else {
+                    cmd::track::resume(&env).await
+                }
+            };
+            match play().await {
+                Err(err)
+                    if ApiError::is_player_error(
+                        &err,
+                        model::PlayerErrorReason::NoActiveDevice,
+                    ) =>
+                {
+                    cmd::agent::activate(&env).await?;
+                    play().await
+                }
+                result => result,
             }
         }
         SubCommands::Stop => cmd::track::stop(&env).await,
+        SubCommands::Toggle => match cmd::track::toggle(&env).await {
+            Err(err)
+                if ApiError::is_player_error(&err, model::PlayerErrorReason::NoActiveDevice) =>
+            {
+                cmd::agent::activate(&env).await?;
+                cmd::track::resume(&env).await
+            }
+            result => result,
+        },
         SubCommands::NextTrack => cmd::track::next(&env).await,
         SubCommands::PreviousTrack => cmd::track::prev(&env).await,
         SubCommands::Open => cmd::open::open(),
-        //_ => unimplemented!(),
+        SubCommands::Search {
+            query,
+            types,
+            limit,
+        } => cmd::search::search(&env, &query, &types, limit).await,
+        SubCommands::Queue(Queue {
+            subcommand: None | Some(QueueSubCommands::List),
+        }) => cmd::queue::list(&env).await,
+
+        SubCommands::Queue(Queue {
+            subcommand: Some(QueueSubCommands::Add { uris }),
+        }) => cmd::queue::add(&env, &uris).await,
+
+        SubCommands::Seek { position } => cmd::track::seek(&env, position).await,
+        SubCommands::Volume { volume } => cmd::track::volume(&env, volume).await,
+        SubCommands::Shuffle { state } => cmd::track::shuffle(&env, state).await,
+        SubCommands::Repeat { state } => cmd::track::repeat(&env, state).await,
+
+        SubCommands::Like { uri } => cmd::library::like(&env, uri.as_ref()).await,
+        SubCommands::Unlike { uri } => cmd::library::unlike(&env, uri.as_ref()).await,
+
+        SubCommands::Auth(Auth {
+            subcommand: None | Some(AuthSubCommands::Status),
+        }) => cmd::auth::status(&env).await,
+
+        SubCommands::Auth(Auth {
+            subcommand: Some(AuthSubCommands::Logout),
+        }) => cmd::auth::logout(&env).await,
+
+        SubCommands::Art { size, out, show } => {
+            cmd::art::art(&env, size, out.as_deref(), show).await
+        }
+
+        SubCommands::Status => {
+            if !cmd::status::status(&env).await? {
+                std::process::exit(1);
+            }
+            Ok(())
+        }
     }
 }
@@ -1,13 +1,28 @@
-use serde::{Deserialize, Serialize};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize};
 
 #[derive(Debug, Serialize, Default)]
 pub struct StartResumeAUsersPlaybackRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub context_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub uris: Option<Vec<String>>,
-    pub offset: Option<()>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<Offset>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub position_ms: Option<u64>,
 }
 
+/// Where to start playback in context.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum Offset {
+    /// Zero based index of item.
+    Position { position: u64 },
+    /// Uri of item.
+    Uri { uri: String },
+}
+
 #[derive(Debug, Serialize)]
 pub struct TransferUserPlaybackRequest {
     pub device_ids: Vec<String>,
@@ -24,7 +39,7 @@ pub struct Device {
     pub name: String,
     #[serde(default)]
     pub r#type: String,
-    pub volume_percent: u8,
+    pub volume_percent: Option<u8>,
 }
 
 /// https://developer.spotify.com/documentation/web-api/reference/#object-devicesobject
@@ -79,9 +94,9 @@ pub struct SimplifiedArtist {
 /// https://developer.spotify.com/documentation/web-api/reference/#object-imageobject
 #[derive(Debug, Deserialize)]
 pub struct Image {
-    //height: Option<u64>,
-    //url: String,
-    //width: Option<u64>,
+    pub height: Option<u64>,
+    pub url: String,
+    pub width: Option<u64>,
 }
 
 /// https://developer.spotify.com/documentation/web-api/reference/#object-albumrestrictionobject
@@ -168,6 +183,7 @@ pub struct Track {
     pub id: String,
     #[serde(default)]
     pub is_local: bool,
+    /// Only present if market is given.
     pub is_playable: Option<bool>,
     //pub linked_from:
     pub name: String,
@@ -222,7 +238,7 @@ pub struct SimplifiedShow {
 /// https://developer.spotify.com/documentation/web-api/reference/#object-episodeobject
 #[derive(Debug, Deserialize)]
 pub struct Episode {
-    pub audio_preview_url: String,
+    pub audio_preview_url: Option<String>,
     pub description: String,
     pub duration_ms: u64,
     pub explicit: bool,
@@ -234,13 +250,13 @@ pub struct Episode {
     pub images: Vec<Image>,
     pub is_externally_hosted: bool,
     pub is_playable: Option<bool>,
-    pub language: String,
+    pub language: Option<String>,
     pub languages: Vec<String>,
     pub name: String,
     pub release_date: String,
     pub release_date_precision: String,
     pub restrictions: Option<EpisodeRestriction>,
-    pub resume_point: ResumePoint,
+    pub resume_point: Option<ResumePoint>,
     pub show: SimplifiedShow,
     #[serde(default)]
     pub r#type: String,
@@ -253,10 +269,52 @@ pub struct EpisodeRestriction {
     pub reason: String,
 }
 
-// FIXME
+/// https://developer.spotify.com/documentation/web-api/reference/#object-simplifiedepisodeobject
 #[derive(Debug, Deserialize)]
-#[serde(tag = "type")]
+pub struct SimplifiedEpisode {
+    pub audio_preview_url: Option<String>,
+    pub description: String,
+    pub duration_ms: u64,
+    pub explicit: bool,
+    pub external_urls: ExternalUrl,
+    pub href: String,
+    pub html_description: String,
+    pub id: String,
+    #[serde(default)]
+    pub images: Vec<Image>,
+    pub is_externally_hosted: bool,
+    pub is_playable: Option<bool>,
+    #[serde(default)]
+    pub languages: Vec<String>,
+    pub name: String,
+    pub release_date: String,
+    pub release_date_precision: String,
+    pub restrictions: Option<EpisodeRestriction>,
+    pub resume_point: Option<ResumePoint>,
+    #[serde(default)]
+    pub r#type: String,
+    pub uri: String,
+}
+
+/// Playable item. (`type` is `track` or `episode`)
+#[derive(Debug)]
 pub enum TrackOrEpisode {
+    Track {
+        inner: Track,
+    },
+    Episode {
+        inner: Episode,
+    },
+    /// Local file. Its type is `track`, but most of the fields are null.
+    Local {
+        inner: LocalTrack,
+    },
+}
+
+/// `TrackOrEpisode` except local files.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type")]
+enum RemoteTrackOrEpisode {
     #[serde(rename = "track")]
     Track {
         #[serde(flatten)]
@@ -269,6 +327,26 @@ pub enum TrackOrEpisode {
     },
 }
 
+impl<'de> Deserialize<'de> for TrackOrEpisode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        if value.get("is_local") == Some(&serde_json::Value::Bool(true)) {
+            return serde_json::from_value(value)
+                .map(|inner| Self::Local { inner })
+                .map_err(D::Error::custom);
+        }
+        Ok(
+            match serde_json::from_value(value).map_err(D::Error::custom)? {
+                RemoteTrackOrEpisode::Track { inner } => Self::Track { inner },
+                RemoteTrackOrEpisode::Episode { inner } => Self::Episode { inner },
+            },
+        )
+    }
+}
+
 /// https://developer.spotify.com/documentation/web-api/reference/#object-currentlyplayingcontextobject
 #[derive(Debug, Deserialize)]
 pub struct CurrentlyPlayingContext {
@@ -277,13 +355,19 @@ pub struct CurrentlyPlayingContext {
     pub currently_playing_type: String,
     pub device: Device,
     pub is_playing: bool,
-    pub item: TrackOrEpisode,
+    pub item: Option<TrackOrEpisode>,
     pub progress_ms: Option<u64>,
     pub repeat_state: String,
     pub shuffle_state: bool,
     pub timestamp: u64,
 }
 
+/// https://developer.spotify.com/documentation/web-api/reference/#/operations/get-queue
+#[derive(Debug, Deserialize)]
+pub struct Queue {
+    pub queue: Vec<TrackOrEpisode>,
+}
+
 /// https://developer.spotify.com/documentation/web-api/reference/#object-publicuserobject
 #[derive(Debug, Deserialize)]
 pub struct PublicUser {
@@ -306,9 +390,39 @@ pub struct PlaylistTrack {
     pub added_by: Option<PublicUser>,
     #[serde(default)]
     pub is_local: bool,
+    /// `None` if removed or unavailable.
     pub track: Option<TrackOrEpisode>,
 }
 
+/// https://developer.spotify.com/documentation/web-api/concepts/playlists#local-files
+#[derive(Debug, Deserialize)]
+pub struct LocalTrack {
+    pub album: Option<LocalName>,
+    #[serde(default)]
+    pub artists: Vec<LocalName>,
+    #[serde(default)]
+    pub duration_ms: u64,
+    pub name: String,
+    /// e.g. `spotify:local:{artist}:{album}:{name}:{duration}`
+    pub uri: String,
+}
+
+impl LocalTrack {
+    pub fn artist_names(&self) -> impl Iterator<Item = &String> {
+        self.artists.iter().filter_map(|a| a.name.as_ref())
+    }
+
+    pub fn album_name(&self) -> Option<&String> {
+        self.album.as_ref().and_then(|a| a.name.as_ref())
+    }
+}
+
+/// Album or artist of local file.
+#[derive(Debug, Deserialize)]
+pub struct LocalName {
+    pub name: Option<String>,
+}
+
 /// https://developer.spotify.com/documentation/web-api/reference/#object-playlistobject
 #[derive(Debug, Deserialize)]
 pub struct Playlist {
@@ -330,6 +444,33 @@ pub struct Playlist {
     pub uri: String,
 }
 
+/// https://developer.spotify.com/documentation/web-api/reference/#object-playlisttracksrefobject
+#[derive(Debug, Deserialize)]
+pub struct PlaylistTracksRef {
+    pub href: String,
+    pub total: u64,
+}
+
+/// https://developer.spotify.com/documentation/web-api/reference/#object-simplifiedplaylistobject
+#[derive(Debug, Deserialize)]
+pub struct SimplifiedPlaylist {
+    pub collaborative: bool,
+    pub description: Option<String>,
+    pub external_urls: ExternalUrl,
+    pub href: String,
+    pub id: String,
+    #[serde(default)]
+    pub images: Vec<Image>,
+    pub name: String,
+    pub owner: PublicUser,
+    pub public: Option<bool>,
+    pub snapshot_id: String,
+    pub tracks: Option<PlaylistTracksRef>,
+    #[serde(default)]
+    pub r#type: String,
+    pub uri: String,
+}
+
 /// https://developer.spotify.com/documentation/web-api/reference/#object-pagingobject
 #[derive(Debug, Deserialize)]
 pub struct Paging<T> {
@@ -409,3 +550,56 @@ pub struct PrivateUser {
 pub struct Tracks {
     pub tracks: Vec<Track>,
 }
+
+/// https://developer.spotify.com/documentation/web-api/reference/#/operations/search
+#[derive(Debug, Deserialize)]
+pub struct SearchResult {
+    pub tracks: Option<Paging<Track>>,
+    pub artists: Option<Paging<Artist>>,
+    pub albums: Option<Paging<SimplifiedAlbum>>,
+    pub playlists: Option<Paging<Option<SimplifiedPlaylist>>>, // may contain null
+    pub shows: Option<Paging<Option<SimplifiedShow>>>,         // may contain null
+    pub episodes: Option<Paging<Option<SimplifiedEpisode>>>,   // may contain null
+}
+
+/// https://developer.spotify.com/documentation/web-api/reference/#object-playererrorreasons
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PlayerErrorReason {
+    NoPrevTrack,
+    NoNextTrack,
+    NoSpecificTrack,
+    AlreadyPaused,
+    NotPaused,
+    NotPlayingLocally,
+    NotPlayingTrack,
+    NotPlayingContext,
+    EndlessContext,
+    ContextDisallow,
+    AlreadyPlaying,
+    RateLimited,
+    RemoteControlDisallow,
+    DeviceNotControllable,
+    VolumeControlDisallow,
+    NoActiveDevice,
+    PremiumRequired,
+    #[serde(other)]
+    Unknown,
+}
+
+/// https://developer.spotify.com/documentation/web-api/reference/#object-errorobject
+/// https://developer.spotify.com/documentation/web-api/reference/#object-playererrorobject
+#[derive(Debug, Deserialize)]
+pub struct Error {
+    pub status: u16,
+    pub message: String,
+    pub reason: Option<PlayerErrorReason>,
+}
+
+/// Error response body of Web API.
+///
+/// https://developer.spotify.com/documentation/web-api/concepts/api-calls#response-schema
+#[derive(Debug, Deserialize)]
+pub struct ErrorResponse {
+    pub error: Error,
+}
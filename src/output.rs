@@ -0,0 +1,453 @@
+//! Output formats shared by commands printing data.
+use std::str::FromStr;
+
+use serde_json::{Map, Value};
+
+use crate::{model, position};
+
+/// Output format selected by `--output`.
+#[derive(Debug, Clone, Default)]
+pub enum OutputFormat {
+    /// Human readable text.
+    #[default]
+    Text,
+    /// One JSON object per line.
+    Json,
+    /// Tab separated values. One record per line.
+    Tsv,
+    /// User supplied template. (e.g. `{artist} - {name}`)
+    Template(String),
+}
+
+impl FromStr for OutputFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "text" => Self::Text,
+            "json" => Self::Json,
+            "tsv" => Self::Tsv,
+            s if s.contains('{') => Self::Template(s.into()),
+            s => anyhow::bail!("unknown output format: {}", s),
+        })
+    }
+}
+
+impl OutputFormat {
+    pub fn print<R>(&self, record: &R) -> anyhow::Result<()>
+    where
+        R: Record + ?Sized,
+    {
+        match self {
+            Self::Text => println!("{}", record.text()),
+            Self::Json => {
+                let object = record
+                    .fields()
+                    .into_iter()
+                    .map(|(k, v)| (k.to_string(), v))
+                    .collect::<Map<_, _>>();
+                println!("{}", serde_json::to_string(&object)?);
+            }
+            Self::Tsv => {
+                let line = record
+                    .fields()
+                    .iter()
+                    .map(|(_, v)| to_plain(v).replace(['\t', '\n'], " "))
+                    .collect::<Vec<_>>()
+                    .join("\t");
+                println!("{}", line);
+            }
+            Self::Template(template) => println!("{}", render(template, &record.fields())?),
+        }
+        Ok(())
+    }
+}
+
+/// Data printed by commands.
+pub trait Record {
+    /// Named fields in output order. Used by json, tsv and template format.
+    fn fields(&self) -> Vec<(&'static str, Value)>;
+
+    /// Human readable representation.
+    fn text(&self) -> String;
+}
+
+fn to_plain(value: &Value) -> String {
+    match value {
+        Value::Null => "".into(),
+        Value::String(s) => s.clone(),
+        v => v.to_string(),
+    }
+}
+
+fn render(template: &str, fields: &[(&'static str, Value)]) -> anyhow::Result<String> {
+    let mut result = String::new();
+    let mut rest = template;
+    while let Some(begin) = rest.find('{') {
+        result.push_str(&rest[..begin]);
+        let end = rest[begin..]
+            .find('}')
+            .ok_or_else(|| anyhow::anyhow!("unclosed `{{` in template: {}", template))?;
+        let key = &rest[begin + 1..begin + end];
+        // Records of different types may be mixed. Missing field is rendered as empty.
+        if let Some((_, value)) = fields.iter().find(|(k, _)| *k == key) {
+            result.push_str(&to_plain(value));
+        }
+        rest = &rest[begin + end + 1..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+/// Fields of items printed in listings. (list, queue, search)
+///
+/// Every item has all of them in this order so that tsv columns and template keys are stable
+/// even if item types are mixed. Fields not applicable to the item are null.
+const ITEM_FIELDS: &[&str] = &[
+    "type",
+    "uri",
+    "name",
+    "artist",
+    "album",
+    "owner",
+    "show",
+    "publisher",
+    "release_date",
+    "duration_ms",
+    "is_playable",
+    "resume_position_ms",
+    "fully_played",
+];
+
+fn item_fields(fields: Vec<(&'static str, Value)>) -> Vec<(&'static str, Value)> {
+    debug_assert!(fields.iter().all(|(k, _)| ITEM_FIELDS.contains(k)));
+    ITEM_FIELDS
+        .iter()
+        .map(|key| {
+            let value = fields
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.clone())
+                .unwrap_or(Value::Null);
+            (*key, value)
+        })
+        .collect()
+}
+
+pub(crate) fn join_names<'a, I>(names: I, sep: &str) -> String
+where
+    I: IntoIterator<Item = &'a String>,
+{
+    names
+        .into_iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(sep)
+}
+
+/// ` [played]` or ` [resume at 12:34]`. Empty if not started.
+fn resume_text(resume_point: &Option<model::ResumePoint>) -> String {
+    match resume_point {
+        Some(r) if r.fully_played => " [played]".into(),
+        Some(r) if r.resume_position_ms > 0 => {
+            format!(" [resume at {}]", position::format(r.resume_position_ms))
+        }
+        _ => "".into(),
+    }
+}
+
+impl Record for model::Device {
+    fn fields(&self) -> Vec<(&'static str, Value)> {
+        vec![
+            ("id", self.id.clone().into()),
+            ("name", self.name.clone().into()),
+            ("type", self.r#type.clone().into()),
+            ("is_active", self.is_active.into()),
+            ("volume_percent", self.volume_percent.into()),
+        ]
+    }
+
+    fn text(&self) -> String {
+        if self.is_active {
+            format!("{} {}✔", self.id, self.name)
+        } else {
+            format!("{} {}", self.id, self.name)
+        }
+    }
+}
+
+impl Record for model::Track {
+    fn fields(&self) -> Vec<(&'static str, Value)> {
+        item_fields(vec![
+            ("type", "track".into()),
+            ("uri", self.uri.clone().into()),
+            ("name", self.name.clone().into()),
+            (
+                "artist",
+                join_names(self.artists.iter().map(|a| &a.name), ", ").into(),
+            ),
+            ("album", self.album.as_ref().map(|a| a.name.clone()).into()),
+            ("duration_ms", self.duration_ms.into()),
+            ("is_playable", self.is_playable.into()),
+        ])
+    }
+
+    fn text(&self) -> String {
+        let text = format!(
+            "{} {} ({})",
+            self.uri,
+            self.name,
+            join_names(self.artists.iter().map(|a| &a.name), " ")
+        );
+        if self.is_playable == Some(false) {
+            format!("{} [unavailable]", text)
+        } else {
+            text
+        }
+    }
+}
+
+impl Record for model::LocalTrack {
+    fn fields(&self) -> Vec<(&'static str, Value)> {
+        item_fields(vec![
+            ("type", "local".into()),
+            ("uri", self.uri.clone().into()),
+            ("name", self.name.clone().into()),
+            ("artist", join_names(self.artist_names(), ", ").into()),
+            ("album", self.album_name().cloned().into()),
+            ("duration_ms", self.duration_ms.into()),
+            ("is_playable", false.into()),
+        ])
+    }
+
+    fn text(&self) -> String {
+        format!(
+            "{} {} ({}) [local]",
+            self.uri,
+            self.name,
+            join_names(self.artist_names(), " ")
+        )
+    }
+}
+
+impl Record for model::PlaylistTrack {
+    fn fields(&self) -> Vec<(&'static str, Value)> {
+        match &self.track {
+            Some(item) => item.fields(),
+            None => item_fields(vec![
+                ("type", "unavailable".into()),
+                ("is_playable", false.into()),
+            ]),
+        }
+    }
+
+    fn text(&self) -> String {
+        match &self.track {
+            Some(item) => item.text(),
+            None => "- [unavailable]".into(),
+        }
+    }
+}
+
+impl Record for model::SimplifiedAlbum {
+    fn fields(&self) -> Vec<(&'static str, Value)> {
+        item_fields(vec![
+            ("type", "album".into()),
+            ("uri", self.uri.clone().into()),
+            ("name", self.name.clone().into()),
+            (
+                "artist",
+                join_names(self.artists.iter().map(|a| &a.name), ", ").into(),
+            ),
+            ("release_date", self.release_date.clone().into()),
+        ])
+    }
+
+    fn text(&self) -> String {
+        format!(
+            "{} {} ({})",
+            self.uri,
+            self.name,
+            join_names(self.artists.iter().map(|a| &a.name), " ")
+        )
+    }
+}
+
+impl Record for model::Artist {
+    fn fields(&self) -> Vec<(&'static str, Value)> {
+        item_fields(vec![
+            ("type", "artist".into()),
+            ("uri", self.uri.clone().into()),
+            ("name", self.name.clone().into()),
+        ])
+    }
+
+    fn text(&self) -> String {
+        format!("{} {}", self.uri, self.name)
+    }
+}
+
+impl Record for model::SimplifiedPlaylist {
+    fn fields(&self) -> Vec<(&'static str, Value)> {
+        item_fields(vec![
+            ("type", "playlist".into()),
+            ("uri", self.uri.clone().into()),
+            ("name", self.name.clone().into()),
+            ("owner", self.owner_name().into()),
+        ])
+    }
+
+    fn text(&self) -> String {
+        format!("{} {} ({})", self.uri, self.name, self.owner_name())
+    }
+}
+
+impl model::SimplifiedPlaylist {
+    fn owner_name(&self) -> String {
+        self.owner
+            .display_name
+            .clone()
+            .unwrap_or_else(|| self.owner.id.clone())
+    }
+}
+
+impl Record for model::SimplifiedShow {
+    fn fields(&self) -> Vec<(&'static str, Value)> {
+        item_fields(vec![
+            ("type", "show".into()),
+            ("uri", self.uri.clone().into()),
+            ("name", self.name.clone().into()),
+            ("publisher", self.publisher.clone().into()),
+        ])
+    }
+
+    fn text(&self) -> String {
+        format!("{} {} ({})", self.uri, self.name, self.publisher)
+    }
+}
+
+impl Record for model::SimplifiedEpisode {
+    fn fields(&self) -> Vec<(&'static str, Value)> {
+        item_fields(vec![
+            ("type", "episode".into()),
+            ("uri", self.uri.clone().into()),
+            ("name", self.name.clone().into()),
+            ("release_date", self.release_date.clone().into()),
+            ("duration_ms", self.duration_ms.into()),
+            (
+                "resume_position_ms",
+                self.resume_point
+                    .as_ref()
+                    .map(|r| r.resume_position_ms)
+                    .into(),
+            ),
+            (
+                "fully_played",
+                self.resume_point.as_ref().map(|r| r.fully_played).into(),
+            ),
+        ])
+    }
+
+    fn text(&self) -> String {
+        format!(
+            "{} {} ({}){}",
+            self.uri,
+            self.name,
+            self.release_date,
+            resume_text(&self.resume_point)
+        )
+    }
+}
+
+impl Record for model::Episode {
+    fn fields(&self) -> Vec<(&'static str, Value)> {
+        item_fields(vec![
+            ("type", "episode".into()),
+            ("uri", self.uri.clone().into()),
+            ("name", self.name.clone().into()),
+            ("show", self.show.name.clone().into()),
+            ("publisher", self.show.publisher.clone().into()),
+            ("release_date", self.release_date.clone().into()),
+            ("duration_ms", self.duration_ms.into()),
+            (
+                "resume_position_ms",
+                self.resume_point
+                    .as_ref()
+                    .map(|r| r.resume_position_ms)
+                    .into(),
+            ),
+            (
+                "fully_played",
+                self.resume_point.as_ref().map(|r| r.fully_played).into(),
+            ),
+        ])
+    }
+
+    fn text(&self) -> String {
+        format!(
+            "{} {} ({}){}",
+            self.uri,
+            self.name,
+            self.show.name,
+            resume_text(&self.resume_point)
+        )
+    }
+}
+
+impl Record for model::TrackOrEpisode {
+    fn fields(&self) -> Vec<(&'static str, Value)> {
+        match self {
+            Self::Track { inner } => inner.fields(),
+            Self::Episode { inner } => inner.fields(),
+            Self::Local { inner } => inner.fields(),
+        }
+    }
+
+    fn text(&self) -> String {
+        match self {
+            Self::Track { inner } => inner.text(),
+            Self::Episode { inner } => inner.text(),
+            Self::Local { inner } => inner.text(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing;
+
+    fn items() -> Vec<Box<dyn Record>> {
+        let track =
+            serde_json::from_value::<model::Track>(testing::track(testing::TRACK_ID, "Song"));
+        let mut episode = testing::episode("5555555555555555555555", "Talk");
+        episode["show"] = testing::show("6666666666666666666666");
+        let episode = serde_json::from_value::<model::Episode>(episode);
+        let removed =
+            serde_json::from_value::<model::PlaylistTrack>(serde_json::json!({ "track": null }));
+        vec![
+            Box::new(track.unwrap()),
+            Box::new(episode.unwrap()),
+            Box::new(removed.unwrap()),
+        ]
+    }
+
+    #[test]
+    fn item_fields_are_same_for_all_types() {
+        for item in items() {
+            let keys = item.fields().iter().map(|(k, _)| *k).collect::<Vec<_>>();
+            assert_eq!(keys, ITEM_FIELDS);
+        }
+    }
+
+    #[test]
+    fn template_renders_missing_field_as_empty() {
+        let rendered = items()
+            .iter()
+            .map(|item| render("{artist} - {name}", &item.fields()).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(rendered, vec!["Artist - Song", " - Talk", " - "]);
+        assert_eq!(render("{nothing}", &[]).unwrap(), "");
+        assert!(render("{name", &[]).is_err());
+    }
+}
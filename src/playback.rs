@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use librespot::connect::spirc::Spirc;
 use librespot::core::cache::Cache;
 use librespot::core::config::ConnectConfig;
@@ -10,8 +12,12 @@ use librespot::playback::{audio_backend, mixer};
 use librespot::protocol::authentication::AuthenticationType;
 use tokio::sync::oneshot;
 
+/// Device name of playback agent.
+pub const DEVICE_NAME: &str = "sptty";
+
 pub async fn connect(
     token: &str,
+    audio_cache: PathBuf,
     mut ready: Option<oneshot::Sender<String>>,
 ) -> anyhow::Result<()> {
     let session_config = SessionConfig {
@@ -26,7 +32,7 @@ pub async fn connect(
     let connect_config = ConnectConfig {
         autoplay: false,
         device_type: DeviceType::Computer,
-        name: "sptty".into(),
+        name: DEVICE_NAME.into(),
         initial_volume: Some(0x5999), // 35%
         has_volume_ctrl: false,
     };
@@ -37,7 +43,6 @@ pub async fn connect(
         auth_type: AuthenticationType::AUTHENTICATION_SPOTIFY_TOKEN,
         auth_data: token.into(),
     };
-    let audio_cache = dirs::cache_dir().unwrap().join("sptty/audio");
     let cache = Cache::new(None, None, Some(audio_cache), None)?;
 
     let (session, _) = Session::connect(session_config, credentials, Some(cache), false).await?;
@@ -0,0 +1,110 @@
+//! Playback position notation. (e.g. `83`, `1:23`, `1:02:03.5`)
+
+/// Parse `[[hours:]minutes:]seconds[.fraction]` into milliseconds.
+///
+/// Plain seconds may have `s` suffix. (e.g. `10s`) Minutes and seconds must be less than 60
+/// unless they are the leading part.
+pub fn parse(s: &str) -> anyhow::Result<u64> {
+    let s = s.trim();
+    let s = s
+        .strip_suffix('s')
+        .filter(|_| !s.contains(':'))
+        .unwrap_or(s);
+    if s.is_empty() {
+        anyhow::bail!("empty position.");
+    }
+
+    let invalid = || anyhow::anyhow!("invalid position: {}", s);
+
+    let mut parts = s.rsplit(':').collect::<Vec<_>>();
+    if parts.len() > 3 {
+        return Err(invalid());
+    }
+    let seconds = parts.remove(0);
+    let (int, frac) = seconds.split_once('.').unwrap_or((seconds, "0"));
+    if !is_digits(int) || !is_digits(frac) {
+        return Err(invalid());
+    }
+    let seconds = seconds.parse::<f64>().map_err(|_| invalid())?;
+
+    let ms = (seconds * 1000.0).round();
+    if ms >= u64::MAX as f64 {
+        return Err(invalid());
+    }
+    let mut ms = ms as u64;
+    let mut lower = seconds;
+    for (part, unit) in parts.into_iter().zip([60_000, 3_600_000]) {
+        if lower >= 60.0 || !is_digits(part) {
+            return Err(invalid());
+        }
+        let value = part.parse::<u64>().map_err(|_| invalid())?;
+        ms = value
+            .checked_mul(unit)
+            .and_then(|v| v.checked_add(ms))
+            .ok_or_else(invalid)?;
+        lower = value as f64;
+    }
+    Ok(ms)
+}
+
+fn is_digits(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Format milliseconds as `[hours:]minutes:seconds`.
+pub fn format(ms: u64) -> String {
+    let secs = ms / 1000;
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_valid() {
+        assert_eq!(parse("83").unwrap(), 83_000);
+        assert_eq!(parse("10s").unwrap(), 10_000);
+        assert_eq!(parse("1.5").unwrap(), 1_500);
+        assert_eq!(parse("1:23").unwrap(), 83_000);
+        assert_eq!(parse("75:00").unwrap(), 4_500_000);
+        assert_eq!(parse("1:02:03.5").unwrap(), 3_723_500);
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for s in [
+            "",
+            "+10s",
+            "-10",
+            "1:75",
+            "1:60:00",
+            "10sss",
+            "1:23s",
+            "1:2:3:4",
+            "1.",
+            ".5",
+            "a",
+            "1::2",
+            "inf",
+            "99999999999999999:00",
+            "9999999999999:00:00",
+            "1e30",
+            "99999999999999999999",
+        ] {
+            assert!(parse(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn format_position() {
+        assert_eq!(format(0), "0:00");
+        assert_eq!(format(83_999), "1:23");
+        assert_eq!(format(3_723_500), "1:02:03");
+    }
+}
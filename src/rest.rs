@@ -1,13 +1,31 @@
-use hyper::header::CONTENT_LENGTH;
-use reqwest::{Client, Url};
-use serde::{de::DeserializeOwned, Serialize};
+use std::fmt;
+use std::time::Duration;
 
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use hyper::header::{CONTENT_LENGTH, RETRY_AFTER};
+use rand::Rng;
+use reqwest::{Client, Response, StatusCode, Url};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use tokio::sync::Mutex;
+use tokio::time;
+
+use super::auth::{ensure_scopes, refresh_access_token};
 use super::get_token;
+use super::model::{ErrorResponse, Paging, PlayerErrorReason};
 
+#[derive(Debug, Clone, Copy)]
 pub enum Method {
     Get,
     Post,
     Put,
+    Delete,
+}
+
+impl Method {
+    /// True if sending the request twice has the same effect as once.
+    fn is_idempotent(&self) -> bool {
+        !matches!(self, Self::Post)
+    }
 }
 
 pub trait Input {
@@ -72,25 +90,201 @@ where
     }
 }
 
+/// Web API failure.
+#[derive(Debug)]
+pub enum ApiError {
+    /// Regular error object.
+    Regular { status: u16, message: String },
+    /// Player error object.
+    Player {
+        status: u16,
+        message: String,
+        reason: PlayerErrorReason,
+    },
+    /// Response body is not an error object.
+    Unknown { status: u16, body: String },
+}
+
+impl ApiError {
+    async fn from_response(res: Response) -> anyhow::Result<Self> {
+        let status = res.status().as_u16();
+        let body = res.text().await?;
+        let err = match serde_json::from_str::<ErrorResponse>(&body) {
+            Ok(ErrorResponse { error }) => match error.reason {
+                Some(reason) => Self::Player {
+                    status: error.status,
+                    message: error.message,
+                    reason,
+                },
+                None => Self::Regular {
+                    status: error.status,
+                    message: error.message,
+                },
+            },
+            Err(..) => Self::Unknown { status, body },
+        };
+        Ok(err)
+    }
+
+    pub fn status(&self) -> u16 {
+        match self {
+            Self::Regular { status, .. }
+            | Self::Player { status, .. }
+            | Self::Unknown { status, .. } => *status,
+        }
+    }
+
+    pub fn reason(&self) -> Option<PlayerErrorReason> {
+        if let Self::Player { reason, .. } = self {
+            Some(*reason)
+        } else {
+            None
+        }
+    }
+
+    /// Test if `err` is caused by player error with `reason`.
+    pub fn is_player_error(err: &anyhow::Error, reason: PlayerErrorReason) -> bool {
+        err.downcast_ref::<Self>().and_then(Self::reason) == Some(reason)
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Self::Regular { message, .. } => message,
+            Self::Player { reason, message, .. } => match reason {
+                PlayerErrorReason::NoPrevTrack => "no previous track.",
+                PlayerErrorReason::NoNextTrack => "no next track.",
+                PlayerErrorReason::NoSpecificTrack => "requested track does not exist.",
+                PlayerErrorReason::AlreadyPaused => "already paused.",
+                PlayerErrorReason::NotPaused => "not paused.",
+                PlayerErrorReason::NotPlayingLocally => "not playing on this device.",
+                PlayerErrorReason::NotPlayingTrack => "no track is playing.",
+                PlayerErrorReason::NotPlayingContext => "no playlist, album or artist is playing.",
+                PlayerErrorReason::EndlessContext => "current context is endless.",
+                PlayerErrorReason::ContextDisallow => "not allowed by current context.",
+                PlayerErrorReason::AlreadyPlaying => "already playing.",
+                PlayerErrorReason::RateLimited => "too many requests. try again later.",
+                PlayerErrorReason::RemoteControlDisallow => {
+                    "remote control is not allowed by the device."
+                }
+                PlayerErrorReason::DeviceNotControllable => "the device is not controllable.",
+                PlayerErrorReason::VolumeControlDisallow => {
+                    "volume control is not allowed by the device."
+                }
+                PlayerErrorReason::NoActiveDevice => {
+                    "no active device. start agent by `sptty agent` or select device by `sptty device set`."
+                }
+                PlayerErrorReason::PremiumRequired => "spotify premium is required.",
+                PlayerErrorReason::Unknown => message,
+            },
+            Self::Unknown { body, .. } => body,
+        };
+        write!(f, "{} ({})", message, self.status())
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+/// Retry policy for rate limited (429), server error (5xx) and connection failure.
+///
+/// Non-idempotent requests (POST) are retried only if they were surely not processed.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts including the first one.
+    pub max_attempts: u32,
+    /// Initial backoff delay in milliseconds. Doubled on each retry.
+    pub base_delay_ms: u64,
+    /// Upper limit of backoff delay in milliseconds.
+    ///
+    /// Rate limited request fails immediately if `Retry-After` is longer than this, so that a
+    /// command never blocks longer than this per retry. Listing large playlists may hit the rate
+    /// limit for minutes; raise this to wait it out.
+    pub max_delay_ms: u64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            base_delay_ms: 500,
+            max_delay_ms: 120_000,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Exponential backoff with full jitter.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let ceil = self
+            .base_delay_ms
+            .saturating_mul(1 << attempt.saturating_sub(1).min(16))
+            .min(self.max_delay_ms);
+        Duration::from_millis(rand::thread_rng().gen_range(0..=ceil))
+    }
+
+    /// Delay requested by `Retry-After` header. Fallback to backoff if absent.
+    fn retry_after(&self, res: &Response, attempt: u32) -> Duration {
+        res.headers()
+            .get(RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse::<u64>().ok())
+            .map(Duration::from_secs)
+            .unwrap_or_else(|| self.backoff(attempt))
+    }
+}
+
 pub struct RestClient {
+    env: super::Environment,
     base: Url,
-    token: String,
+    token: Mutex<String>,
     client: Client,
 }
 
 impl RestClient {
     pub async fn new(env: &super::Environment) -> anyhow::Result<Self> {
-        let base = Url::parse(&env.api_endpoint)?;
         let token = get_token(env, super::default_leading_authorization_url).await?;
+        Self::with_token(env, token)
+    }
 
+    /// Client using `token` as is. Rejected token is refreshed, but never logs in.
+    pub fn with_token(env: &super::Environment, token: String) -> anyhow::Result<Self> {
+        let base = Url::parse(&env.api_endpoint)?;
         let client = Client::new();
         Ok(Self {
+            env: env.clone(),
             base,
-            token,
+            token: Mutex::new(token),
             client,
         })
     }
 
+    /// Login again if any of `scopes` is not granted to current access token.
+    pub async fn require_scopes(&self, scopes: &[&str]) -> anyhow::Result<()> {
+        if let Some(token) =
+            ensure_scopes(&self.env, scopes, super::default_leading_authorization_url).await?
+        {
+            *self.token.lock().await = token;
+        }
+        Ok(())
+    }
+
+    async fn send(&self, url: &Url, method: Method, body: &[u8]) -> reqwest::Result<Response> {
+        let req = match method {
+            Method::Get => self.client.get(url.clone()),
+            Method::Post => self.client.post(url.clone()),
+            Method::Put => self.client.put(url.clone()),
+            Method::Delete => self.client.delete(url.clone()),
+        };
+        let token = self.token.lock().await.clone();
+        req.bearer_auth(token)
+            .header(CONTENT_LENGTH, body.len())
+            .body(body.to_vec())
+            .send()
+            .await
+    }
+
     pub async fn request<I, O>(&self, path: &str, method: Method, req: I) -> anyhow::Result<O>
     where
         I: Input,
@@ -100,28 +294,184 @@ impl RestClient {
 
         let body = req.to_json()?;
         log::debug!("request: {}", String::from_utf8_lossy(&body));
-        let req = match method {
-            Method::Get => self.client.get(url),
-            Method::Post => self.client.post(url),
-            Method::Put => self.client.put(url),
+        let retry = &self.env.retry_policy;
+        let mut attempt = 0;
+        let mut refreshed = false;
+        let res = loop {
+            attempt += 1;
+            let res = match self.send(&url, method, &body).await {
+                Ok(res) => res,
+                // Timed out request may have been processed. Retry only if idempotent.
+                Err(err)
+                    if (err.is_connect() || (err.is_timeout() && method.is_idempotent()))
+                        && attempt < retry.max_attempts =>
+                {
+                    let delay = retry.backoff(attempt);
+                    log::debug!("{}. retry after {:?}.", err, delay);
+                    time::sleep(delay).await;
+                    continue;
+                }
+                Err(err) => return Err(err.into()),
+            };
+
+            let status = res.status();
+            if status == StatusCode::UNAUTHORIZED && !refreshed {
+                log::debug!("access token rejected. refresh and retry.");
+                let token = refresh_access_token(&self.env).await?;
+                *self.token.lock().await = token;
+                refreshed = true;
+                attempt -= 1;
+                continue;
+            }
+
+            if attempt < retry.max_attempts {
+                let delay = if status == StatusCode::TOO_MANY_REQUESTS {
+                    let delay = retry.retry_after(&res, attempt);
+                    if delay > Duration::from_millis(retry.max_delay_ms) {
+                        let err = ApiError::from_response(res).await?;
+                        return Err(anyhow::Error::new(err).context(format!(
+                            "rate limited. retry after {} seconds.",
+                            delay.as_secs()
+                        )));
+                    }
+                    Some(delay)
+                } else if status.is_server_error() && method.is_idempotent() {
+                    Some(retry.backoff(attempt))
+                } else {
+                    None
+                };
+                if let Some(delay) = delay {
+                    log::debug!("failed to request({}). retry after {:?}.", status, delay);
+                    time::sleep(delay).await;
+                    continue;
+                }
+            }
+
+            break res;
         };
-        let res = req
-            .bearer_auth(&self.token)
-            .header(CONTENT_LENGTH, body.len())
-            .body(body)
-            .send()
-            .await?;
 
         if !res.status().is_success() {
-            anyhow::bail!(
-                "failed to request({}).: {}",
-                res.status().to_string(),
-                res.text().await?,
-            );
+            return Err(ApiError::from_response(res).await?.into());
         }
 
         let body = res.bytes().await?;
         log::debug!("response: {}", String::from_utf8_lossy(&body));
-        Ok(O::from_json(&body)?)
+        O::from_json(&body)
+    }
+
+    /// Stream items of `Paging` object, following `next` until exhausted or `limit` reached.
+    pub fn paginate<'a, T>(
+        &'a self,
+        path: &str,
+        limit: Option<usize>,
+    ) -> impl Stream<Item = anyhow::Result<T>> + 'a
+    where
+        T: DeserializeOwned + 'a,
+    {
+        stream::try_unfold(Some(path.to_string()), move |next| async move {
+            if let Some(next) = next {
+                let page = self
+                    .request::<_, Paging<T>>(&next, Method::Get, Empty)
+                    .await?;
+                let items = stream::iter(page.items.into_iter().map(Ok));
+                anyhow::Result::<_>::Ok(Some((items, page.next)))
+            } else {
+                Ok(None)
+            }
+        })
+        .try_flatten()
+        .take(limit.unwrap_or(usize::MAX))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::FakeSpotify;
+
+    const RATE_LIMITED: &str = r#"{"error":{"status":429,"message":"API rate limit exceeded"}}"#;
+    const SERVER_ERROR: &str = r#"{"error":{"status":502,"message":"Bad gateway."}}"#;
+
+    async fn client(fake: &FakeSpotify, max_attempts: u32) -> RestClient {
+        let mut env = fake.env.clone();
+        env.retry_policy = RetryPolicy {
+            max_attempts,
+            base_delay_ms: 1,
+            max_delay_ms: 1000,
+        };
+        RestClient::new(&env).await.unwrap()
+    }
+
+    fn count(fake: &FakeSpotify, path: &str) -> usize {
+        fake.requests().iter().filter(|r| r.uri == path).count()
+    }
+
+    #[tokio::test]
+    async fn rate_limited_is_retried_after_delay() {
+        let fake = FakeSpotify::start().await;
+        fake.route_with_headers(
+            "POST",
+            "/v1/me/player/next",
+            429,
+            &[("retry-after", "0")],
+            RATE_LIMITED,
+        );
+        let client = client(&fake, 3).await;
+
+        let err = client
+            .request::<_, Empty>("/v1/me/player/next", Method::Post, Empty)
+            .await
+            .unwrap_err();
+        assert_eq!(err.downcast_ref::<ApiError>().unwrap().status(), 429);
+        // Not processed by server. Safe to retry even if not idempotent.
+        assert_eq!(count(&fake, "/v1/me/player/next"), 3);
+    }
+
+    #[tokio::test]
+    async fn long_retry_after_fails_immediately() {
+        let fake = FakeSpotify::start().await;
+        fake.route_with_headers(
+            "GET",
+            "/v1/me/player/devices",
+            429,
+            &[("retry-after", "3600")],
+            RATE_LIMITED,
+        );
+        let client = client(&fake, 3).await;
+
+        let err = client
+            .request::<_, Empty>("/v1/me/player/devices", Method::Get, Empty)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("3600 seconds"), "{}", err);
+        assert_eq!(err.downcast_ref::<ApiError>().unwrap().status(), 429);
+        assert_eq!(count(&fake, "/v1/me/player/devices"), 1);
+    }
+
+    #[tokio::test]
+    async fn server_error_is_retried_up_to_max_attempts() {
+        let fake = FakeSpotify::start().await;
+        fake.route("GET", "/v1/me/player/devices", 502, SERVER_ERROR);
+        let client = client(&fake, 4).await;
+
+        let err = client
+            .request::<_, Empty>("/v1/me/player/devices", Method::Get, Empty)
+            .await
+            .unwrap_err();
+        assert_eq!(err.downcast_ref::<ApiError>().unwrap().status(), 502);
+        assert_eq!(count(&fake, "/v1/me/player/devices"), 4);
+    }
+
+    #[tokio::test]
+    async fn server_error_is_not_retried_if_not_idempotent() {
+        let fake = FakeSpotify::start().await;
+        fake.route("POST", "/v1/me/player/next", 502, SERVER_ERROR);
+        let client = client(&fake, 4).await;
+
+        assert!(client
+            .request::<_, Empty>("/v1/me/player/next", Method::Post, Empty)
+            .await
+            .is_err());
+        assert_eq!(count(&fake, "/v1/me/player/next"), 1);
     }
 }
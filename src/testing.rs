@@ -0,0 +1,476 @@
+//! In-process fake of Spotify Web API and accounts service for tests.
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use hyper::{server::conn::Http, service::service_fn, Body, Request, Response};
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tokio::net::TcpListener;
+
+use crate::output::OutputFormat;
+use crate::rest::RetryPolicy;
+use crate::Environment;
+
+pub const TRACK_ID: &str = "4iV5W9uYEdYUVa79Axb7Rh";
+pub const PLAYLIST_ID: &str = "37i9dQZF1DXcBWIGoYBM5M";
+pub const DEVICE_ID: &str = "d0e1v2i3c4e5";
+
+/// Request received by `FakeSpotify`.
+#[derive(Debug, Clone)]
+pub struct Recorded {
+    pub method: String,
+    /// Path and query.
+    pub uri: String,
+    pub body: String,
+}
+
+impl Recorded {
+    pub fn json(&self) -> Value {
+        serde_json::from_str(&self.body).unwrap()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Route {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+#[derive(Default)]
+struct State {
+    /// (method, path or path with query) -> response
+    routes: HashMap<(String, String), Route>,
+    requests: Vec<Recorded>,
+}
+
+/// Fake server with `Environment` pointing to it.
+///
+/// `GET` without route responds 404. Other methods without route respond 204.
+pub struct FakeSpotify {
+    pub addr: SocketAddr,
+    pub env: Environment,
+    state: Arc<Mutex<State>>,
+    _cache_dir: TempDir,
+}
+
+impl FakeSpotify {
+    /// Start server with default routes and logged in token cache.
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let state = Arc::new(Mutex::new(State::default()));
+
+        let shared = state.clone();
+        tokio::spawn(async move {
+            loop {
+                let (sock, _) = listener.accept().await.unwrap();
+                let state = shared.clone();
+                let func = service_fn(move |req| handle(req, state.clone()));
+                tokio::spawn(Http::new().serve_connection(sock, func));
+            }
+        });
+
+        let cache_dir = tempfile::tempdir().unwrap();
+        let auth_config = toml::from_str(&format!(
+            r#"
+client_id = "client"
+redirect_uri = "http://127.0.0.1:1/callback"
+authorization_endpoint = "http://{addr}/authorize"
+token_endpoint = "http://{addr}/api/token"
+no_browser = true
+login_timeout_secs = 1
+"#,
+            addr = addr
+        ))
+        .unwrap();
+        let env = Environment {
+            auth_config,
+            api_endpoint: format!("http://{}", addr),
+            retry_policy: RetryPolicy {
+                max_attempts: 2,
+                base_delay_ms: 1,
+                max_delay_ms: 1,
+            },
+            output: OutputFormat::default(),
+            cache_dir: cache_dir.path().into(),
+            profile: None,
+        };
+
+        let fake = Self {
+            addr,
+            env,
+            state,
+            _cache_dir: cache_dir,
+        };
+        fake.login(3600);
+        fake.default_routes();
+        fake
+    }
+
+    /// Write cached token expiring after `expires_in` seconds.
+    pub fn login(&self, expires_in: i64) {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+        let token = json!({
+            "access_token": "cached-access",
+            "token_type": "Bearer",
+            "scope": self.env.auth_config.scopes.join(" "),
+            "expires_in": 3600,
+            "refresh_token": "cached-refresh",
+            "expires_at": (now + expires_in).max(0),
+        });
+        let path = self.env.token_cache_path();
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, token.to_string()).unwrap();
+    }
+
+    /// Respond `body` with `status` to `method` `path`. `path` may contain query to match exactly.
+    pub fn route<B>(&self, method: &str, path: &str, status: u16, body: B)
+    where
+        B: ToString,
+    {
+        self.route_with_headers(method, path, status, &[], body);
+    }
+
+    /// Same as `route` but also responds `headers`.
+    pub fn route_with_headers<B>(
+        &self,
+        method: &str,
+        path: &str,
+        status: u16,
+        headers: &[(&str, &str)],
+        body: B,
+    ) where
+        B: ToString,
+    {
+        let route = Route {
+            status,
+            headers: headers
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            body: body.to_string(),
+        };
+        self.state
+            .lock()
+            .unwrap()
+            .routes
+            .insert((method.into(), path.into()), route);
+    }
+
+    /// Requests received so far.
+    pub fn requests(&self) -> Vec<Recorded> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    /// Requests received so far except `GET`.
+    pub fn commands(&self) -> Vec<Recorded> {
+        self.requests()
+            .into_iter()
+            .filter(|r| r.method != "GET")
+            .collect()
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("http://{}{}", self.addr, path)
+    }
+
+    fn default_routes(&self) {
+        self.route("GET", "/v1/me", 200, user());
+        self.route(
+            "GET",
+            "/v1/me/player",
+            200,
+            playing(&self.url(&format!("/v1/playlists/{}", PLAYLIST_ID))),
+        );
+        self.route(
+            "GET",
+            "/v1/me/player/devices",
+            200,
+            json!({
+                "devices": [
+                    device(DEVICE_ID, "Living Room", true),
+                    device("a1b2c3", "Kitchen", false),
+                ]
+            }),
+        );
+        self.route(
+            "GET",
+            "/v1/me/player/queue",
+            200,
+            json!({
+                "currently_playing": track(TRACK_ID, "Current"),
+                "queue": [track("1111111111111111111111", "Next")],
+            }),
+        );
+        self.route("GET", "/v1/me/tracks/contains", 200, json!([true]));
+        self.route(
+            "GET",
+            &format!("/v1/playlists/{}/tracks", PLAYLIST_ID),
+            200,
+            paging(
+                vec![
+                    json!({ "track": track(TRACK_ID, "First") }),
+                    json!({ "track": track("2222222222222222222222", "Second") }),
+                ],
+                Some(self.url(&format!("/v1/playlists/{}/tracks?offset=2", PLAYLIST_ID))),
+            ),
+        );
+        self.route(
+            "GET",
+            &format!("/v1/playlists/{}/tracks?offset=2", PLAYLIST_ID),
+            200,
+            paging(
+                vec![json!({ "track": track("3333333333333333333333", "Third") })],
+                None,
+            ),
+        );
+        self.route(
+            "POST",
+            "/api/token",
+            200,
+            json!({
+                "access_token": "refreshed-access",
+                "token_type": "Bearer",
+                "expires_in": 3600,
+            }),
+        );
+    }
+}
+
+async fn handle(req: Request<Body>, state: Arc<Mutex<State>>) -> anyhow::Result<Response<Body>> {
+    let method = req.method().to_string();
+    let uri = req
+        .uri()
+        .path_and_query()
+        .map(|p| p.to_string())
+        .unwrap_or_default();
+    let path = req.uri().path().to_string();
+    let body = hyper::body::to_bytes(req.into_body()).await?;
+
+    let mut state = state.lock().unwrap();
+    state.requests.push(Recorded {
+        method: method.clone(),
+        uri: uri.clone(),
+        body: String::from_utf8_lossy(&body).into_owned(),
+    });
+    let route = state
+        .routes
+        .get(&(method.clone(), uri))
+        .or_else(|| state.routes.get(&(method.clone(), path)))
+        .cloned();
+    let route = match route {
+        Some(route) => route,
+        None if method == "GET" => Route {
+            status: 404,
+            headers: vec![],
+            body: json!({ "error": { "status": 404, "message": "Not found." } }).to_string(),
+        },
+        None => Route {
+            status: 204,
+            headers: vec![],
+            body: String::new(),
+        },
+    };
+    let mut res = Response::builder()
+        .status(route.status)
+        .header("content-type", "application/json");
+    for (key, value) in &route.headers {
+        res = res.header(key, value);
+    }
+    Ok(res.body(Body::from(route.body))?)
+}
+
+pub fn user() -> Value {
+    json!({
+        "country": "JP",
+        "display_name": "Test User",
+        "email": "test@example.com",
+        "explicit_content": { "filter_enabled": false, "filter_locked": false },
+        "external_urls": { "spotify": "https://open.spotify.com/user/test" },
+        "followers": { "href": null, "total": 0 },
+        "href": "https://api.spotify.com/v1/users/test",
+        "id": "test",
+        "images": [],
+        "product": "premium",
+        "type": "user",
+        "uri": "spotify:user:test",
+    })
+}
+
+pub fn device(id: &str, name: &str, is_active: bool) -> Value {
+    json!({
+        "id": id,
+        "is_active": is_active,
+        "is_private_session": false,
+        "is_restricted": false,
+        "name": name,
+        "type": "Computer",
+        "volume_percent": 50,
+    })
+}
+
+pub fn track(id: &str, name: &str) -> Value {
+    json!({
+        "album": {
+            "album_type": "album",
+            "artists": [artist()],
+            "external_urls": { "spotify": "https://open.spotify.com/album/6akEvsycLGftJxYudPjmqK" },
+            "href": "https://api.spotify.com/v1/albums/6akEvsycLGftJxYudPjmqK",
+            "id": "6akEvsycLGftJxYudPjmqK",
+            "images": [],
+            "name": "Album",
+            "release_date": "2020-01-01",
+            "release_date_precision": "day",
+            "total_tracks": 10,
+            "type": "album",
+            "uri": "spotify:album:6akEvsycLGftJxYudPjmqK",
+        },
+        "artists": [artist()],
+        "disc_number": 1,
+        "duration_ms": 200000,
+        "explicit": false,
+        "external_urls": { "spotify": format!("https://open.spotify.com/track/{}", id) },
+        "href": format!("https://api.spotify.com/v1/tracks/{}", id),
+        "id": id,
+        "is_local": false,
+        "name": name,
+        "track_number": 1,
+        "type": "track",
+        "uri": format!("spotify:track:{}", id),
+    })
+}
+
+/// Local file. Most fields are null or empty.
+pub fn local_track(name: &str) -> Value {
+    json!({
+        "album": {
+            "album_type": null,
+            "artists": [],
+            "available_markets": [],
+            "external_urls": {},
+            "href": null,
+            "id": null,
+            "images": [],
+            "name": "Local Album",
+            "release_date": null,
+            "release_date_precision": null,
+            "type": "album",
+            "uri": null,
+        },
+        "artists": [{
+            "external_urls": {},
+            "href": null,
+            "id": null,
+            "name": "Local Artist",
+            "type": "artist",
+            "uri": null,
+        }],
+        "available_markets": [],
+        "disc_number": 0,
+        "duration_ms": 180000,
+        "explicit": false,
+        "external_ids": {},
+        "external_urls": {},
+        "href": null,
+        "id": null,
+        "is_local": true,
+        "name": name,
+        "popularity": 0,
+        "preview_url": null,
+        "track_number": 0,
+        "type": "track",
+        "uri": format!("spotify:local:Local+Artist:Local+Album:{}:180", name),
+    })
+}
+
+/// Simplified episode. Add `show` to make full episode.
+pub fn episode(id: &str, name: &str) -> Value {
+    json!({
+        "description": "",
+        "duration_ms": 1800000,
+        "explicit": false,
+        "external_urls": { "spotify": format!("https://open.spotify.com/episode/{}", id) },
+        "href": format!("https://api.spotify.com/v1/episodes/{}", id),
+        "html_description": "",
+        "id": id,
+        "images": [],
+        "is_externally_hosted": false,
+        "languages": ["en"],
+        "name": name,
+        "release_date": "2022-01-01",
+        "release_date_precision": "day",
+        "resume_point": { "fully_played": false, "resume_position_ms": 83000 },
+        "type": "episode",
+        "uri": format!("spotify:episode:{}", id),
+    })
+}
+
+pub fn show(id: &str) -> Value {
+    json!({
+        "copyrights": [],
+        "description": "",
+        "explicit": false,
+        "external_urls": { "spotify": format!("https://open.spotify.com/show/{}", id) },
+        "href": format!("https://api.spotify.com/v1/shows/{}", id),
+        "html_description": "",
+        "id": id,
+        "images": [],
+        "is_externally_hosted": false,
+        "languages": ["en"],
+        "media_type": "audio",
+        "name": "Show",
+        "publisher": "Publisher",
+        "type": "show",
+        "uri": format!("spotify:show:{}", id),
+    })
+}
+
+fn artist() -> Value {
+    json!({
+        "external_urls": { "spotify": "https://open.spotify.com/artist/0OdUWJ0sBjDrqHygGUXeCF" },
+        "href": "https://api.spotify.com/v1/artists/0OdUWJ0sBjDrqHygGUXeCF",
+        "id": "0OdUWJ0sBjDrqHygGUXeCF",
+        "name": "Artist",
+        "type": "artist",
+        "uri": "spotify:artist:0OdUWJ0sBjDrqHygGUXeCF",
+    })
+}
+
+/// Currently playing context of playlist at `playlist_href`.
+pub fn playing(playlist_href: &str) -> Value {
+    json!({
+        "actions": {},
+        "context": {
+            "external_urls": { "spotify": format!("https://open.spotify.com/playlist/{}", PLAYLIST_ID) },
+            "href": playlist_href,
+            "type": "playlist",
+            "uri": format!("spotify:playlist:{}", PLAYLIST_ID),
+        },
+        "currently_playing_type": "track",
+        "device": device(DEVICE_ID, "Living Room", true),
+        "is_playing": true,
+        "item": track(TRACK_ID, "First"),
+        "progress_ms": 60000,
+        "repeat_state": "off",
+        "shuffle_state": false,
+        "timestamp": 0,
+    })
+}
+
+pub fn paging(items: Vec<Value>, next: Option<String>) -> Value {
+    json!({
+        "href": "",
+        "items": items,
+        "limit": 2,
+        "next": next,
+        "offset": 0,
+        "previous": null,
+        "total": 3,
+    })
+}
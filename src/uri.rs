@@ -0,0 +1,226 @@
+//! Spotify URI (`spotify:track:...`), URL (`https://open.spotify.com/track/...`) and ID.
+use std::fmt;
+use std::str::FromStr;
+
+use reqwest::Url;
+
+/// Type of item identified by `SpotifyId`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemType {
+    Track,
+    Album,
+    Artist,
+    Playlist,
+    Show,
+    Episode,
+    User,
+}
+
+impl ItemType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Track => "track",
+            Self::Album => "album",
+            Self::Artist => "artist",
+            Self::Playlist => "playlist",
+            Self::Show => "show",
+            Self::Episode => "episode",
+            Self::User => "user",
+        }
+    }
+
+    /// True if playable as context. (album, artist, playlist or show)
+    pub fn is_context(&self) -> bool {
+        matches!(
+            self,
+            Self::Album | Self::Artist | Self::Playlist | Self::Show
+        )
+    }
+}
+
+impl FromStr for ItemType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "track" => Self::Track,
+            "album" => Self::Album,
+            "artist" => Self::Artist,
+            "playlist" => Self::Playlist,
+            "show" => Self::Show,
+            "episode" => Self::Episode,
+            "user" => Self::User,
+            s => anyhow::bail!("unknown item type: {}", s),
+        })
+    }
+}
+
+/// Spotify item identifier.
+///
+/// Parsed from `spotify:` URI, `open.spotify.com` URL (including share links) or bare base62 ID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpotifyId {
+    /// `None` if parsed from bare ID.
+    pub item_type: Option<ItemType>,
+    pub id: String,
+}
+
+impl SpotifyId {
+    /// Item type. Bare ID is treated as `default`.
+    pub fn item_type_or(&self, default: ItemType) -> ItemType {
+        self.item_type.unwrap_or(default)
+    }
+
+    /// `spotify:{type}:{id}` URI. Bare ID is treated as `default`.
+    pub fn to_uri(&self, default: ItemType) -> String {
+        format!(
+            "spotify:{}:{}",
+            self.item_type_or(default).as_str(),
+            self.id
+        )
+    }
+
+    fn from_segments(segments: &[&str], s: &str) -> anyhow::Result<Self> {
+        let (item_type, id) = match segments {
+            ["user", _, "playlist", id] => (ItemType::Playlist, *id),
+            [item_type, id] => (item_type.parse()?, *id),
+            _ => anyhow::bail!("invalid spotify uri: {}", s),
+        };
+
+        let valid = match item_type {
+            ItemType::User => !id.is_empty(),
+            _ => is_base62_id(id),
+        };
+        if !valid {
+            anyhow::bail!("invalid spotify id: {}", id);
+        }
+
+        Ok(Self {
+            item_type: Some(item_type),
+            id: id.into(),
+        })
+    }
+}
+
+impl FromStr for SpotifyId {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if let Some(rest) = s.strip_prefix("spotify:") {
+            let segments = rest.split(':').collect::<Vec<_>>();
+            return Self::from_segments(&segments, s);
+        }
+
+        if s.starts_with("https://") || s.starts_with("http://") {
+            let url = Url::parse(s)?;
+            if url.host_str() != Some("open.spotify.com") {
+                anyhow::bail!("not a spotify url: {}", s);
+            }
+            // e.g. /intl-ja/track/{id}, /embed/track/{id}
+            let segments = url
+                .path_segments()
+                .into_iter()
+                .flatten()
+                .filter(|p| !p.is_empty())
+                .skip_while(|p| p.starts_with("intl-") || *p == "embed")
+                .collect::<Vec<_>>();
+            return Self::from_segments(&segments, s);
+        }
+
+        if is_base62_id(s) {
+            return Ok(Self {
+                item_type: None,
+                id: s.into(),
+            });
+        }
+
+        anyhow::bail!("invalid spotify uri, url or id: {}", s)
+    }
+}
+
+impl fmt::Display for SpotifyId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(item_type) = self.item_type {
+            write!(f, "spotify:{}:{}", item_type.as_str(), self.id)
+        } else {
+            write!(f, "{}", self.id)
+        }
+    }
+}
+
+fn is_base62_id(s: &str) -> bool {
+    s.len() == 22 && s.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ID: &str = "4iV5W9uYEdYUVa79Axb7Rh";
+
+    fn parse(s: &str) -> SpotifyId {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn uri() {
+        let id = parse(&format!("spotify:track:{}", ID));
+        assert_eq!(id.item_type, Some(ItemType::Track));
+        assert_eq!(id.id, ID);
+        assert_eq!(id.to_string(), format!("spotify:track:{}", ID));
+    }
+
+    #[test]
+    fn user_playlist_uri() {
+        let id = parse(&format!("spotify:user:someone:playlist:{}", ID));
+        assert_eq!(id.item_type, Some(ItemType::Playlist));
+        assert_eq!(id.id, ID);
+    }
+
+    #[test]
+    fn share_link() {
+        let id = parse(&format!(
+            "https://open.spotify.com/album/{}?si=abcdef0123456789",
+            ID
+        ));
+        assert_eq!(id.item_type, Some(ItemType::Album));
+        assert_eq!(id.id, ID);
+    }
+
+    #[test]
+    fn intl_and_embed_url() {
+        let id = parse(&format!("https://open.spotify.com/intl-ja/track/{}", ID));
+        assert_eq!(id.item_type, Some(ItemType::Track));
+        let id = parse(&format!("https://open.spotify.com/embed/playlist/{}", ID));
+        assert_eq!(id.item_type, Some(ItemType::Playlist));
+        assert_eq!(id.id, ID);
+    }
+
+    #[test]
+    fn bare_id() {
+        let id = parse(ID);
+        assert_eq!(id.item_type, None);
+        assert_eq!(
+            id.to_uri(ItemType::Episode),
+            format!("spotify:episode:{}", ID)
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let invalid = [
+            format!("https://example.com/track/{}", ID),
+            format!("spotify:track:{}", &ID[1..]),
+            format!("spotify:track:{}x", ID),
+            ID[1..].to_string(),
+            format!("spotify:podcast:{}", ID),
+            format!("https://open.spotify.com/podcast/{}", ID),
+            "spotify:track".to_string(),
+        ];
+        for s in &invalid {
+            assert!(s.parse::<SpotifyId>().is_err(), "{}", s);
+        }
+    }
+}
@@ -0,0 +1,1791 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `mercury.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MercuryMultiGetRequest {
+    // message fields
+    pub request: ::protobuf::RepeatedField<MercuryRequest>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MercuryMultiGetRequest {
+    fn default() -> &'a MercuryMultiGetRequest {
+        <MercuryMultiGetRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MercuryMultiGetRequest {
+    pub fn new() -> MercuryMultiGetRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated .MercuryRequest request = 1;
+
+
+    pub fn get_request(&self) -> &[MercuryRequest] {
+        &self.request
+    }
+    pub fn clear_request(&mut self) {
+        self.request.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_request(&mut self, v: ::protobuf::RepeatedField<MercuryRequest>) {
+        self.request = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_request(&mut self) -> &mut ::protobuf::RepeatedField<MercuryRequest> {
+        &mut self.request
+    }
+
+    // Take field
+    pub fn take_request(&mut self) -> ::protobuf::RepeatedField<MercuryRequest> {
+        ::std::mem::replace(&mut self.request, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for MercuryMultiGetRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.request {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.request)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.request {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.request {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MercuryMultiGetRequest {
+        MercuryMultiGetRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<MercuryRequest>>(
+                "request",
+                |m: &MercuryMultiGetRequest| { &m.request },
+                |m: &mut MercuryMultiGetRequest| { &mut m.request },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MercuryMultiGetRequest>(
+                "MercuryMultiGetRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MercuryMultiGetRequest {
+        static instance: ::protobuf::rt::LazyV2<MercuryMultiGetRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MercuryMultiGetRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for MercuryMultiGetRequest {
+    fn clear(&mut self) {
+        self.request.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MercuryMultiGetRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MercuryMultiGetRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MercuryMultiGetReply {
+    // message fields
+    pub reply: ::protobuf::RepeatedField<MercuryReply>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MercuryMultiGetReply {
+    fn default() -> &'a MercuryMultiGetReply {
+        <MercuryMultiGetReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MercuryMultiGetReply {
+    pub fn new() -> MercuryMultiGetReply {
+        ::std::default::Default::default()
+    }
+
+    // repeated .MercuryReply reply = 1;
+
+
+    pub fn get_reply(&self) -> &[MercuryReply] {
+        &self.reply
+    }
+    pub fn clear_reply(&mut self) {
+        self.reply.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reply(&mut self, v: ::protobuf::RepeatedField<MercuryReply>) {
+        self.reply = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_reply(&mut self) -> &mut ::protobuf::RepeatedField<MercuryReply> {
+        &mut self.reply
+    }
+
+    // Take field
+    pub fn take_reply(&mut self) -> ::protobuf::RepeatedField<MercuryReply> {
+        ::std::mem::replace(&mut self.reply, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for MercuryMultiGetReply {
+    fn is_initialized(&self) -> bool {
+        for v in &self.reply {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.reply)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.reply {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.reply {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MercuryMultiGetReply {
+        MercuryMultiGetReply::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<MercuryReply>>(
+                "reply",
+                |m: &MercuryMultiGetReply| { &m.reply },
+                |m: &mut MercuryMultiGetReply| { &mut m.reply },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MercuryMultiGetReply>(
+                "MercuryMultiGetReply",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MercuryMultiGetReply {
+        static instance: ::protobuf::rt::LazyV2<MercuryMultiGetReply> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MercuryMultiGetReply::new)
+    }
+}
+
+impl ::protobuf::Clear for MercuryMultiGetReply {
+    fn clear(&mut self) {
+        self.reply.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MercuryMultiGetReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MercuryMultiGetReply {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MercuryRequest {
+    // message fields
+    uri: ::protobuf::SingularField<::std::string::String>,
+    content_type: ::protobuf::SingularField<::std::string::String>,
+    body: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    etag: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MercuryRequest {
+    fn default() -> &'a MercuryRequest {
+        <MercuryRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MercuryRequest {
+    pub fn new() -> MercuryRequest {
+        ::std::default::Default::default()
+    }
+
+    // optional string uri = 1;
+
+
+    pub fn get_uri(&self) -> &str {
+        match self.uri.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_uri(&mut self) {
+        self.uri.clear();
+    }
+
+    pub fn has_uri(&self) -> bool {
+        self.uri.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_uri(&mut self, v: ::std::string::String) {
+        self.uri = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_uri(&mut self) -> &mut ::std::string::String {
+        if self.uri.is_none() {
+            self.uri.set_default();
+        }
+        self.uri.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_uri(&mut self) -> ::std::string::String {
+        self.uri.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional string content_type = 2;
+
+
+    pub fn get_content_type(&self) -> &str {
+        match self.content_type.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_content_type(&mut self) {
+        self.content_type.clear();
+    }
+
+    pub fn has_content_type(&self) -> bool {
+        self.content_type.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content_type(&mut self, v: ::std::string::String) {
+        self.content_type = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_content_type(&mut self) -> &mut ::std::string::String {
+        if self.content_type.is_none() {
+            self.content_type.set_default();
+        }
+        self.content_type.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_content_type(&mut self) -> ::std::string::String {
+        self.content_type.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional bytes body = 3;
+
+
+    pub fn get_body(&self) -> &[u8] {
+        match self.body.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+    pub fn clear_body(&mut self) {
+        self.body.clear();
+    }
+
+    pub fn has_body(&self) -> bool {
+        self.body.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_body(&mut self, v: ::std::vec::Vec<u8>) {
+        self.body = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_body(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.body.is_none() {
+            self.body.set_default();
+        }
+        self.body.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_body(&mut self) -> ::std::vec::Vec<u8> {
+        self.body.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    // optional bytes etag = 4;
+
+
+    pub fn get_etag(&self) -> &[u8] {
+        match self.etag.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+    pub fn clear_etag(&mut self) {
+        self.etag.clear();
+    }
+
+    pub fn has_etag(&self) -> bool {
+        self.etag.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_etag(&mut self, v: ::std::vec::Vec<u8>) {
+        self.etag = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_etag(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.etag.is_none() {
+            self.etag.set_default();
+        }
+        self.etag.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_etag(&mut self) -> ::std::vec::Vec<u8> {
+        self.etag.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for MercuryRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.uri)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.content_type)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.body)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.etag)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.uri.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(ref v) = self.content_type.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(ref v) = self.body.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(3, &v);
+        }
+        if let Some(ref v) = self.etag.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(4, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.uri.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(ref v) = self.content_type.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(ref v) = self.body.as_ref() {
+            os.write_bytes(3, &v)?;
+        }
+        if let Some(ref v) = self.etag.as_ref() {
+            os.write_bytes(4, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MercuryRequest {
+        MercuryRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "uri",
+                |m: &MercuryRequest| { &m.uri },
+                |m: &mut MercuryRequest| { &mut m.uri },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "content_type",
+                |m: &MercuryRequest| { &m.content_type },
+                |m: &mut MercuryRequest| { &mut m.content_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "body",
+                |m: &MercuryRequest| { &m.body },
+                |m: &mut MercuryRequest| { &mut m.body },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "etag",
+                |m: &MercuryRequest| { &m.etag },
+                |m: &mut MercuryRequest| { &mut m.etag },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MercuryRequest>(
+                "MercuryRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MercuryRequest {
+        static instance: ::protobuf::rt::LazyV2<MercuryRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MercuryRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for MercuryRequest {
+    fn clear(&mut self) {
+        self.uri.clear();
+        self.content_type.clear();
+        self.body.clear();
+        self.etag.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MercuryRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MercuryRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MercuryReply {
+    // message fields
+    status_code: ::std::option::Option<i32>,
+    status_message: ::protobuf::SingularField<::std::string::String>,
+    cache_policy: ::std::option::Option<MercuryReply_CachePolicy>,
+    ttl: ::std::option::Option<i32>,
+    etag: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    content_type: ::protobuf::SingularField<::std::string::String>,
+    body: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MercuryReply {
+    fn default() -> &'a MercuryReply {
+        <MercuryReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MercuryReply {
+    pub fn new() -> MercuryReply {
+        ::std::default::Default::default()
+    }
+
+    // optional sint32 status_code = 1;
+
+
+    pub fn get_status_code(&self) -> i32 {
+        self.status_code.unwrap_or(0)
+    }
+    pub fn clear_status_code(&mut self) {
+        self.status_code = ::std::option::Option::None;
+    }
+
+    pub fn has_status_code(&self) -> bool {
+        self.status_code.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status_code(&mut self, v: i32) {
+        self.status_code = ::std::option::Option::Some(v);
+    }
+
+    // optional string status_message = 2;
+
+
+    pub fn get_status_message(&self) -> &str {
+        match self.status_message.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_status_message(&mut self) {
+        self.status_message.clear();
+    }
+
+    pub fn has_status_message(&self) -> bool {
+        self.status_message.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status_message(&mut self, v: ::std::string::String) {
+        self.status_message = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_status_message(&mut self) -> &mut ::std::string::String {
+        if self.status_message.is_none() {
+            self.status_message.set_default();
+        }
+        self.status_message.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_status_message(&mut self) -> ::std::string::String {
+        self.status_message.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional .MercuryReply.CachePolicy cache_policy = 3;
+
+
+    pub fn get_cache_policy(&self) -> MercuryReply_CachePolicy {
+        self.cache_policy.unwrap_or(MercuryReply_CachePolicy::CACHE_NO)
+    }
+    pub fn clear_cache_policy(&mut self) {
+        self.cache_policy = ::std::option::Option::None;
+    }
+
+    pub fn has_cache_policy(&self) -> bool {
+        self.cache_policy.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cache_policy(&mut self, v: MercuryReply_CachePolicy) {
+        self.cache_policy = ::std::option::Option::Some(v);
+    }
+
+    // optional sint32 ttl = 4;
+
+
+    pub fn get_ttl(&self) -> i32 {
+        self.ttl.unwrap_or(0)
+    }
+    pub fn clear_ttl(&mut self) {
+        self.ttl = ::std::option::Option::None;
+    }
+
+    pub fn has_ttl(&self) -> bool {
+        self.ttl.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ttl(&mut self, v: i32) {
+        self.ttl = ::std::option::Option::Some(v);
+    }
+
+    // optional bytes etag = 5;
+
+
+    pub fn get_etag(&self) -> &[u8] {
+        match self.etag.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+    pub fn clear_etag(&mut self) {
+        self.etag.clear();
+    }
+
+    pub fn has_etag(&self) -> bool {
+        self.etag.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_etag(&mut self, v: ::std::vec::Vec<u8>) {
+        self.etag = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_etag(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.etag.is_none() {
+            self.etag.set_default();
+        }
+        self.etag.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_etag(&mut self) -> ::std::vec::Vec<u8> {
+        self.etag.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    // optional string content_type = 6;
+
+
+    pub fn get_content_type(&self) -> &str {
+        match self.content_type.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_content_type(&mut self) {
+        self.content_type.clear();
+    }
+
+    pub fn has_content_type(&self) -> bool {
+        self.content_type.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content_type(&mut self, v: ::std::string::String) {
+        self.content_type = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_content_type(&mut self) -> &mut ::std::string::String {
+        if self.content_type.is_none() {
+            self.content_type.set_default();
+        }
+        self.content_type.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_content_type(&mut self) -> ::std::string::String {
+        self.content_type.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional bytes body = 7;
+
+
+    pub fn get_body(&self) -> &[u8] {
+        match self.body.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+    pub fn clear_body(&mut self) {
+        self.body.clear();
+    }
+
+    pub fn has_body(&self) -> bool {
+        self.body.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_body(&mut self, v: ::std::vec::Vec<u8>) {
+        self.body = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_body(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.body.is_none() {
+            self.body.set_default();
+        }
+        self.body.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_body(&mut self) -> ::std::vec::Vec<u8> {
+        self.body.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for MercuryReply {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_sint32()?;
+                    self.status_code = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.status_message)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto2_enum_with_unknown_fields_into(wire_type, is, &mut self.cache_policy, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_sint32()?;
+                    self.ttl = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.etag)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.content_type)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.body)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.status_code {
+            my_size += ::protobuf::rt::value_varint_zigzag_size(1, v);
+        }
+        if let Some(ref v) = self.status_message.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(v) = self.cache_policy {
+            my_size += ::protobuf::rt::enum_size(3, v);
+        }
+        if let Some(v) = self.ttl {
+            my_size += ::protobuf::rt::value_varint_zigzag_size(4, v);
+        }
+        if let Some(ref v) = self.etag.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(5, &v);
+        }
+        if let Some(ref v) = self.content_type.as_ref() {
+            my_size += ::protobuf::rt::string_size(6, &v);
+        }
+        if let Some(ref v) = self.body.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(7, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.status_code {
+            os.write_sint32(1, v)?;
+        }
+        if let Some(ref v) = self.status_message.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(v) = self.cache_policy {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&v))?;
+        }
+        if let Some(v) = self.ttl {
+            os.write_sint32(4, v)?;
+        }
+        if let Some(ref v) = self.etag.as_ref() {
+            os.write_bytes(5, &v)?;
+        }
+        if let Some(ref v) = self.content_type.as_ref() {
+            os.write_string(6, &v)?;
+        }
+        if let Some(ref v) = self.body.as_ref() {
+            os.write_bytes(7, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MercuryReply {
+        MercuryReply::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeSint32>(
+                "status_code",
+                |m: &MercuryReply| { &m.status_code },
+                |m: &mut MercuryReply| { &mut m.status_code },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "status_message",
+                |m: &MercuryReply| { &m.status_message },
+                |m: &mut MercuryReply| { &mut m.status_message },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<MercuryReply_CachePolicy>>(
+                "cache_policy",
+                |m: &MercuryReply| { &m.cache_policy },
+                |m: &mut MercuryReply| { &mut m.cache_policy },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeSint32>(
+                "ttl",
+                |m: &MercuryReply| { &m.ttl },
+                |m: &mut MercuryReply| { &mut m.ttl },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "etag",
+                |m: &MercuryReply| { &m.etag },
+                |m: &mut MercuryReply| { &mut m.etag },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "content_type",
+                |m: &MercuryReply| { &m.content_type },
+                |m: &mut MercuryReply| { &mut m.content_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "body",
+                |m: &MercuryReply| { &m.body },
+                |m: &mut MercuryReply| { &mut m.body },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MercuryReply>(
+                "MercuryReply",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MercuryReply {
+        static instance: ::protobuf::rt::LazyV2<MercuryReply> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MercuryReply::new)
+    }
+}
+
+impl ::protobuf::Clear for MercuryReply {
+    fn clear(&mut self) {
+        self.status_code = ::std::option::Option::None;
+        self.status_message.clear();
+        self.cache_policy = ::std::option::Option::None;
+        self.ttl = ::std::option::Option::None;
+        self.etag.clear();
+        self.content_type.clear();
+        self.body.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MercuryReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MercuryReply {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum MercuryReply_CachePolicy {
+    CACHE_NO = 1,
+    CACHE_PRIVATE = 2,
+    CACHE_PUBLIC = 3,
+}
+
+impl ::protobuf::ProtobufEnum for MercuryReply_CachePolicy {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<MercuryReply_CachePolicy> {
+        match value {
+            1 => ::std::option::Option::Some(MercuryReply_CachePolicy::CACHE_NO),
+            2 => ::std::option::Option::Some(MercuryReply_CachePolicy::CACHE_PRIVATE),
+            3 => ::std::option::Option::Some(MercuryReply_CachePolicy::CACHE_PUBLIC),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [MercuryReply_CachePolicy] = &[
+            MercuryReply_CachePolicy::CACHE_NO,
+            MercuryReply_CachePolicy::CACHE_PRIVATE,
+            MercuryReply_CachePolicy::CACHE_PUBLIC,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<MercuryReply_CachePolicy>("MercuryReply.CachePolicy", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for MercuryReply_CachePolicy {
+}
+
+// Note, `Default` is implemented although default value is not 0
+impl ::std::default::Default for MercuryReply_CachePolicy {
+    fn default() -> Self {
+        MercuryReply_CachePolicy::CACHE_NO
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MercuryReply_CachePolicy {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Header {
+    // message fields
+    uri: ::protobuf::SingularField<::std::string::String>,
+    content_type: ::protobuf::SingularField<::std::string::String>,
+    method: ::protobuf::SingularField<::std::string::String>,
+    status_code: ::std::option::Option<i32>,
+    pub user_fields: ::protobuf::RepeatedField<UserField>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Header {
+    fn default() -> &'a Header {
+        <Header as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Header {
+    pub fn new() -> Header {
+        ::std::default::Default::default()
+    }
+
+    // optional string uri = 1;
+
+
+    pub fn get_uri(&self) -> &str {
+        match self.uri.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_uri(&mut self) {
+        self.uri.clear();
+    }
+
+    pub fn has_uri(&self) -> bool {
+        self.uri.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_uri(&mut self, v: ::std::string::String) {
+        self.uri = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_uri(&mut self) -> &mut ::std::string::String {
+        if self.uri.is_none() {
+            self.uri.set_default();
+        }
+        self.uri.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_uri(&mut self) -> ::std::string::String {
+        self.uri.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional string content_type = 2;
+
+
+    pub fn get_content_type(&self) -> &str {
+        match self.content_type.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_content_type(&mut self) {
+        self.content_type.clear();
+    }
+
+    pub fn has_content_type(&self) -> bool {
+        self.content_type.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content_type(&mut self, v: ::std::string::String) {
+        self.content_type = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_content_type(&mut self) -> &mut ::std::string::String {
+        if self.content_type.is_none() {
+            self.content_type.set_default();
+        }
+        self.content_type.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_content_type(&mut self) -> ::std::string::String {
+        self.content_type.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional string method = 3;
+
+
+    pub fn get_method(&self) -> &str {
+        match self.method.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_method(&mut self) {
+        self.method.clear();
+    }
+
+    pub fn has_method(&self) -> bool {
+        self.method.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_method(&mut self, v: ::std::string::String) {
+        self.method = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_method(&mut self) -> &mut ::std::string::String {
+        if self.method.is_none() {
+            self.method.set_default();
+        }
+        self.method.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_method(&mut self) -> ::std::string::String {
+        self.method.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional sint32 status_code = 4;
+
+
+    pub fn get_status_code(&self) -> i32 {
+        self.status_code.unwrap_or(0)
+    }
+    pub fn clear_status_code(&mut self) {
+        self.status_code = ::std::option::Option::None;
+    }
+
+    pub fn has_status_code(&self) -> bool {
+        self.status_code.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status_code(&mut self, v: i32) {
+        self.status_code = ::std::option::Option::Some(v);
+    }
+
+    // repeated .UserField user_fields = 6;
+
+
+    pub fn get_user_fields(&self) -> &[UserField] {
+        &self.user_fields
+    }
+    pub fn clear_user_fields(&mut self) {
+        self.user_fields.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_fields(&mut self, v: ::protobuf::RepeatedField<UserField>) {
+        self.user_fields = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_user_fields(&mut self) -> &mut ::protobuf::RepeatedField<UserField> {
+        &mut self.user_fields
+    }
+
+    // Take field
+    pub fn take_user_fields(&mut self) -> ::protobuf::RepeatedField<UserField> {
+        ::std::mem::replace(&mut self.user_fields, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Header {
+    fn is_initialized(&self) -> bool {
+        for v in &self.user_fields {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.uri)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.content_type)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.method)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_sint32()?;
+                    self.status_code = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.user_fields)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.uri.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(ref v) = self.content_type.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(ref v) = self.method.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(v) = self.status_code {
+            my_size += ::protobuf::rt::value_varint_zigzag_size(4, v);
+        }
+        for value in &self.user_fields {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.uri.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(ref v) = self.content_type.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(ref v) = self.method.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        if let Some(v) = self.status_code {
+            os.write_sint32(4, v)?;
+        }
+        for v in &self.user_fields {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Header {
+        Header::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "uri",
+                |m: &Header| { &m.uri },
+                |m: &mut Header| { &mut m.uri },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "content_type",
+                |m: &Header| { &m.content_type },
+                |m: &mut Header| { &mut m.content_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "method",
+                |m: &Header| { &m.method },
+                |m: &mut Header| { &mut m.method },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeSint32>(
+                "status_code",
+                |m: &Header| { &m.status_code },
+                |m: &mut Header| { &mut m.status_code },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UserField>>(
+                "user_fields",
+                |m: &Header| { &m.user_fields },
+                |m: &mut Header| { &mut m.user_fields },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Header>(
+                "Header",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Header {
+        static instance: ::protobuf::rt::LazyV2<Header> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Header::new)
+    }
+}
+
+impl ::protobuf::Clear for Header {
+    fn clear(&mut self) {
+        self.uri.clear();
+        self.content_type.clear();
+        self.method.clear();
+        self.status_code = ::std::option::Option::None;
+        self.user_fields.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Header {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Header {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UserField {
+    // message fields
+    key: ::protobuf::SingularField<::std::string::String>,
+    value: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UserField {
+    fn default() -> &'a UserField {
+        <UserField as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UserField {
+    pub fn new() -> UserField {
+        ::std::default::Default::default()
+    }
+
+    // optional string key = 1;
+
+
+    pub fn get_key(&self) -> &str {
+        match self.key.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    pub fn has_key(&self) -> bool {
+        self.key.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        if self.key.is_none() {
+            self.key.set_default();
+        }
+        self.key.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        self.key.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional bytes value = 2;
+
+
+    pub fn get_value(&self) -> &[u8] {
+        match self.value.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    pub fn has_value(&self) -> bool {
+        self.value.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.value.is_none() {
+            self.value.set_default();
+        }
+        self.value.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        self.value.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for UserField {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.value)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.key.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(ref v) = self.value.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(2, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.key.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(ref v) = self.value.as_ref() {
+            os.write_bytes(2, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UserField {
+        UserField::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "key",
+                |m: &UserField| { &m.key },
+                |m: &mut UserField| { &mut m.key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "value",
+                |m: &UserField| { &m.value },
+                |m: &mut UserField| { &mut m.value },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UserField>(
+                "UserField",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UserField {
+        static instance: ::protobuf::rt::LazyV2<UserField> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UserField::new)
+    }
+}
+
+impl ::protobuf::Clear for UserField {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UserField {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UserField {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\rmercury.proto\"G\n\x16MercuryMultiGetRequest\x12+\n\x07request\x18\
+    \x01\x20\x03(\x0b2\x0f.MercuryRequestR\x07requestB\0:\0\"?\n\x14MercuryM\
+    ultiGetReply\x12%\n\x05reply\x18\x01\x20\x03(\x0b2\r.MercuryReplyR\x05re\
+    plyB\0:\0\"w\n\x0eMercuryRequest\x12\x12\n\x03uri\x18\x01\x20\x01(\tR\
+    \x03uriB\0\x12#\n\x0ccontent_type\x18\x02\x20\x01(\tR\x0bcontentTypeB\0\
+    \x12\x14\n\x04body\x18\x03\x20\x01(\x0cR\x04bodyB\0\x12\x14\n\x04etag\
+    \x18\x04\x20\x01(\x0cR\x04etagB\0:\0\"\xc5\x02\n\x0cMercuryReply\x12!\n\
+    \x0bstatus_code\x18\x01\x20\x01(\x11R\nstatusCodeB\0\x12'\n\x0estatus_me\
+    ssage\x18\x02\x20\x01(\tR\rstatusMessageB\0\x12>\n\x0ccache_policy\x18\
+    \x03\x20\x01(\x0e2\x19.MercuryReply.CachePolicyR\x0bcachePolicyB\0\x12\
+    \x12\n\x03ttl\x18\x04\x20\x01(\x11R\x03ttlB\0\x12\x14\n\x04etag\x18\x05\
+    \x20\x01(\x0cR\x04etagB\0\x12#\n\x0ccontent_type\x18\x06\x20\x01(\tR\x0b\
+    contentTypeB\0\x12\x14\n\x04body\x18\x07\x20\x01(\x0cR\x04bodyB\0\"B\n\
+    \x0bCachePolicy\x12\x0c\n\x08CACHE_NO\x10\x01\x12\x11\n\rCACHE_PRIVATE\
+    \x10\x02\x12\x10\n\x0cCACHE_PUBLIC\x10\x03\x1a\0:\0\"\xaf\x01\n\x06Heade\
+    r\x12\x12\n\x03uri\x18\x01\x20\x01(\tR\x03uriB\0\x12#\n\x0ccontent_type\
+    \x18\x02\x20\x01(\tR\x0bcontentTypeB\0\x12\x18\n\x06method\x18\x03\x20\
+    \x01(\tR\x06methodB\0\x12!\n\x0bstatus_code\x18\x04\x20\x01(\x11R\nstatu\
+    sCodeB\0\x12-\n\x0buser_fields\x18\x06\x20\x03(\x0b2\n.UserFieldR\nuserF\
+    ieldsB\0:\0\"9\n\tUserField\x12\x12\n\x03key\x18\x01\x20\x01(\tR\x03keyB\
+    \0\x12\x16\n\x05value\x18\x02\x20\x01(\x0cR\x05valueB\0:\0B\0b\x06proto2\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
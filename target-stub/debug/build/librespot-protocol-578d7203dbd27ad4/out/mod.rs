@@ -0,0 +1,12 @@
+// @generated
+pub mod authentication;
+pub mod keyexchange;
+pub mod mercury;
+pub mod metadata;
+pub mod playlist4changes;
+pub mod playlist4content;
+pub mod playlist4issues;
+pub mod playlist4meta;
+pub mod playlist4ops;
+pub mod pubsub;
+pub mod spirc;